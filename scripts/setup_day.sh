@@ -2,30 +2,32 @@
 
 # Master setup script for a new Advent of Code day
 # Combines creating the template and downloading input
-# Usage: ./scripts/setup_day.sh <day_number>
+# Usage: ./scripts/setup_day.sh <day_number> [year]
 
 if [ -z "$1" ]; then
-    echo "Usage: ./scripts/setup_day.sh <day_number>"
+    echo "Usage: ./scripts/setup_day.sh <day_number> [year]"
     exit 1
 fi
 
 DAY=$1
+# Without a year, the default from aoc.toml (or 2015) applies
+YEAR=${2:-$(cargo run --quiet -- config show | sed -n 's/^year = //p')}
 
-echo "🎄 Setting up Advent of Code 2015 - Day $DAY 🎄"
+echo "🎄 Setting up Advent of Code $YEAR - Day $DAY 🎄"
 echo ""
 
 # Create the day template
 echo "Step 1: Creating day template..."
-./scripts/new_day.sh "$DAY"
+cargo run --quiet -- new --year "$YEAR" --day "$DAY" || exit 1
 
 echo ""
 echo "Step 2: Downloading input..."
-./scripts/download_input.sh "$DAY" 2015
+cargo run --quiet -- fetch --year "$YEAR" --day "$DAY"
 
 echo ""
 echo "✨ Setup complete! ✨"
 echo ""
 echo "Next steps:"
-echo "  1. Implement your solution in src/days/day$(printf "%02d" $DAY).rs"
-echo "  2. Run with: cargo run -- --day $DAY"
-echo "  3. Test with: cargo test day$(printf "%02d" $DAY)"
+echo "  1. Implement your solution in src/days/y$YEAR/day$(printf "%02d" $DAY).rs"
+echo "  2. Run with: cargo run -- --year $YEAR --day $DAY"
+echo "  3. Test with: cargo test y$YEAR::day$(printf "%02d" $DAY)"
@@ -0,0 +1,185 @@
+use super::submit::{Hint, Verdict};
+use crate::days::Answer;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_DIR: &str = "submissions";
+
+/// A submitted answer and the server's verdict
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: Answer,
+    #[serde(flatten)]
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted for one day, stored as `YYYY/dayNN.json`
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Ledger {
+    submissions: Vec<Submission>,
+}
+
+/// Why a candidate answer is not worth submitting
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(Answer),
+    AlreadyWrong,
+    /// At or below an answer that was too low
+    TooLow(Answer),
+    /// At or above an answer that was too high
+    TooHigh(Answer),
+}
+
+/// Path of the ledger for a day
+pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+    dir.join(year.to_string())
+        .join(format!("day{:02}.json", day))
+}
+
+impl Ledger {
+    /// Load a ledger, or an empty ledger if nothing was submitted yet
+    pub fn load(path: &Path) -> io::Result<Ledger> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Ledger::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    pub fn record(&mut self, part: u8, answer: &Answer, verdict: &Verdict) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |d| d.as_secs());
+        self.submissions.push(Submission {
+            part,
+            answer: answer.clone(),
+            verdict: verdict.clone(),
+            submitted_at,
+        });
+    }
+
+    fn submissions(&self, part: u8) -> impl Iterator<Item = &Submission> {
+        self.submissions.iter().filter(move |s| s.part == part)
+    }
+
+    /// The closest answers known to be too low and too high for a part
+    pub fn bounds(&self, part: u8) -> (Option<&Answer>, Option<&Answer>) {
+        let with_hint = |hint: Hint| {
+            self.submissions(part).filter(move |s| {
+                s.verdict == Verdict::Incorrect { hint: Some(hint) } && s.answer.as_i128().is_some()
+            })
+        };
+        let low = with_hint(Hint::TooLow).max_by_key(|s| s.answer.as_i128());
+        let high = with_hint(Hint::TooHigh).min_by_key(|s| s.answer.as_i128());
+        (low.map(|s| &s.answer), high.map(|s| &s.answer))
+    }
+
+    /// Check whether earlier submissions already rule out a candidate answer
+    pub fn check(&self, part: u8, answer: &Answer) -> Result<(), Refusal> {
+        for submission in self.submissions(part) {
+            match submission.verdict {
+                Verdict::Correct => {
+                    return Err(Refusal::AlreadyCorrect(submission.answer.clone()));
+                }
+                Verdict::Incorrect { .. } if answer.matches(&submission.answer) => {
+                    return Err(Refusal::AlreadyWrong);
+                }
+                _ => {}
+            }
+        }
+
+        let Some(value) = answer.as_i128() else {
+            return Ok(());
+        };
+        let (low, high) = self.bounds(part);
+        if let Some(low) = low.filter(|low| low.as_i128() >= Some(value)) {
+            return Err(Refusal::TooLow(low.clone()));
+        }
+        if let Some(high) = high.filter(|high| high.as_i128() <= Some(value)) {
+            return Err(Refusal::TooHigh(high.clone()));
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => write!(f, "already solved with {}", answer),
+            Refusal::AlreadyWrong => write!(f, "already submitted and rejected"),
+            Refusal::TooLow(low) => write!(f, "too low, {} was already too low", low),
+            Refusal::TooHigh(high) => write!(f, "too high, {} was already too high", high),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn incorrect(hint: Option<Hint>) -> Verdict {
+        Verdict::Incorrect { hint }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, &Answer::Int(100), &incorrect(Some(Hint::TooLow)));
+        ledger.record(1, &Answer::Int(500), &incorrect(Some(Hint::TooHigh)));
+        ledger.record(1, &Answer::Int(300), &incorrect(Some(Hint::TooHigh)));
+        ledger.record(1, &Answer::Int(200), &incorrect(None));
+
+        assert_eq!(
+            ledger.check(1, &Answer::Int(200)),
+            Err(Refusal::AlreadyWrong)
+        );
+        assert_eq!(
+            ledger.check(1, &Answer::Int(50)),
+            Err(Refusal::TooLow(Answer::Int(100)))
+        );
+        assert_eq!(
+            ledger.check(1, &Answer::Unsigned(400)),
+            Err(Refusal::TooHigh(Answer::Int(300)))
+        );
+        assert_eq!(ledger.check(1, &Answer::Int(250)), Ok(()));
+        assert_eq!(ledger.check(2, &Answer::Int(50)), Ok(()));
+
+        ledger.record(2, &Answer::Int(42), &Verdict::Correct);
+        assert_eq!(
+            ledger.check(2, &Answer::Int(41)),
+            Err(Refusal::AlreadyCorrect(Answer::Int(42)))
+        );
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let mut ledger = Ledger::default();
+        ledger.record(1, &Answer::Int(100), &incorrect(Some(Hint::TooLow)));
+        ledger.record(1, &Answer::from("abc"), &Verdict::AlreadySolved);
+
+        let json = serde_json::to_string(&ledger).unwrap();
+        assert!(json.contains(r#""verdict":"incorrect","hint":"too_low""#));
+
+        let ledger: Ledger = serde_json::from_str(&json).unwrap();
+        assert_eq!(ledger.submissions.len(), 2);
+        assert_eq!(ledger.submissions[0].answer, Answer::Int(100));
+        assert_eq!(ledger.submissions[0].verdict, incorrect(Some(Hint::TooLow)));
+        assert_eq!(ledger.submissions[1].verdict, Verdict::AlreadySolved);
+    }
+}
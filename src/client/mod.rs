@@ -0,0 +1,298 @@
+pub mod ledger;
+mod submit;
+mod submitter;
+#[cfg(test)]
+mod test_server;
+
+pub use submit::Verdict;
+pub use submitter::{Outcome, SubmitError, Submitted, Submitter};
+
+use reqwest::blocking;
+use reqwest::header::{self, HeaderMap, HeaderValue};
+use reqwest::StatusCode;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Environment variable and `.env` key holding the session cookie
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// Identifies the tool to the Advent of Code servers. The site's automation guidelines ask
+/// for a way to reach whoever runs it, so `contact` (a repository URL or an email address)
+/// is included when given.
+pub fn user_agent(contact: &str) -> String {
+    let name = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+    match contact.trim() {
+        "" => name.to_string(),
+        contact => format!("{} (+{})", name, contact),
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    MissingSession,
+    InvalidSession,
+    Http(reqwest::Error),
+    Status(StatusCode),
+    /// The server's reply to a submission could not be interpreted
+    UnrecognizedResponse,
+    Io(io::Error),
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::MissingSession => write!(
+                f,
+                "no session cookie found; set {} in the environment or in .env",
+                SESSION_VAR
+            ),
+            ClientError::InvalidSession => {
+                write!(f, "the session cookie is not a valid header value")
+            }
+            ClientError::Http(e) => write!(f, "request failed: {}", e),
+            ClientError::Status(status) if *status == StatusCode::NOT_FOUND => {
+                write!(f, "HTTP {}: the puzzle is not available yet", status)
+            }
+            ClientError::Status(status) if status.is_client_error() => write!(
+                f,
+                "HTTP {}: check that your session cookie is valid and not expired",
+                status
+            ),
+            ClientError::Status(status) => write!(f, "HTTP {}", status),
+            ClientError::UnrecognizedResponse => {
+                write!(f, "the server's response could not be understood")
+            }
+            ClientError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+impl From<reqwest::Error> for ClientError {
+    fn from(e: reqwest::Error) -> Self {
+        ClientError::Http(e)
+    }
+}
+
+impl From<io::Error> for ClientError {
+    fn from(e: io::Error) -> Self {
+        ClientError::Io(e)
+    }
+}
+
+/// Find the `AOC_SESSION` value in the contents of a `.env` file
+fn parse_env_file(contents: &str) -> Option<String> {
+    contents
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.starts_with('#'))
+        .filter_map(|line| line.strip_prefix("export ").unwrap_or(line).split_once('='))
+        .find(|(key, _)| key.trim() == SESSION_VAR)
+        .map(|(_, value)| {
+            value
+                .trim()
+                .trim_matches(|c| c == '"' || c == '\'')
+                .to_string()
+        })
+        .filter(|value| !value.is_empty())
+}
+
+/// Read the session cookie from the environment, falling back to a `.env` file
+pub fn load_session(env_file: &Path) -> Result<String, ClientError> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_string());
+        }
+    }
+
+    fs::read_to_string(env_file)
+        .ok()
+        .and_then(|contents| parse_env_file(&contents))
+        .ok_or(ClientError::MissingSession)
+}
+
+/// Result of fetching a puzzle input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded,
+    /// The input was already on disk, so nothing was requested
+    Cached,
+}
+
+/// Client for the Advent of Code website
+pub struct Client {
+    http: blocking::Client,
+    base_url: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str, contact: &str) -> Result<Client, ClientError> {
+        let cookie = HeaderValue::from_str(&format!("session={}", session))
+            .map_err(|_| ClientError::InvalidSession)?;
+        let mut headers = HeaderMap::new();
+        headers.insert(header::COOKIE, cookie);
+
+        let http = blocking::Client::builder()
+            .user_agent(user_agent(contact))
+            .default_headers(headers)
+            .timeout(Duration::from_secs(30))
+            .build()?;
+
+        Ok(Client {
+            http,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    fn url(&self, year: u16, day: u8, path: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url, year, day, path)
+    }
+
+    /// Download the puzzle input for a day
+    pub fn input(&self, year: u16, day: u8) -> Result<String, ClientError> {
+        let response = self.http.get(self.url(year, day, "/input")).send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError::Status(status));
+        }
+        Ok(response.text()?)
+    }
+
+    /// Submit an answer for a part and interpret the server's verdict
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<Verdict, ClientError> {
+        let level = part.to_string();
+        let response = self
+            .http
+            .post(self.url(year, day, "/answer"))
+            .form(&[("level", level.as_str()), ("answer", answer)])
+            .send()?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ClientError::Status(status));
+        }
+        submit::parse_response(&response.text()?).ok_or(ClientError::UnrecognizedResponse)
+    }
+
+    /// Download the puzzle input to `path`, unless a non-empty file is already there
+    pub fn fetch_input(&self, year: u16, day: u8, path: &Path) -> Result<Fetched, ClientError> {
+        if fs::metadata(path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+
+        let input = self.input(year, day)?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::write(path, input)?;
+        Ok(Fetched::Downloaded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use submit::Hint;
+    use test_server::TestServer;
+
+    const CONTACT: &str = "me@example.com";
+
+    #[test]
+    fn test_parse_env_file() {
+        assert_eq!(
+            parse_env_file("# comment\nAOC_SESSION=abc123\n"),
+            Some("abc123".to_string())
+        );
+        assert_eq!(
+            parse_env_file("export AOC_SESSION=\"abc123\""),
+            Some("abc123".to_string())
+        );
+        assert_eq!(parse_env_file("AOC_SESSION=\nOTHER=1"), None);
+        assert_eq!(parse_env_file("#AOC_SESSION=abc"), None);
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let server = TestServer::start(vec![(200, "1x2x3\n".to_string())]);
+        let client = Client::new(&server.url(), "secret", CONTACT).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("inputs/day02.txt");
+
+        assert_eq!(
+            client.fetch_input(2015, 2, &path).unwrap(),
+            Fetched::Downloaded
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1x2x3\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        let request = requests[0].to_lowercase();
+        assert!(request.starts_with("get /2015/day/2/input "));
+        assert!(request.contains("cookie: session=secret"));
+        assert!(request.contains(&format!(
+            "user-agent: {}/{} (+{})",
+            env!("CARGO_PKG_NAME"),
+            env!("CARGO_PKG_VERSION"),
+            CONTACT
+        )));
+    }
+
+    #[test]
+    fn test_fetch_input_uses_cache() {
+        let server = TestServer::start(vec![]);
+        let client = Client::new(&server.url(), "secret", CONTACT).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day02.txt");
+        fs::write(&path, "cached").unwrap();
+
+        assert_eq!(client.fetch_input(2015, 2, &path).unwrap(), Fetched::Cached);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "cached");
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn test_submit() {
+        let body = "<article><p>That's not the right answer; your answer is too low.</p></article>";
+        let server = TestServer::start(vec![(200, body.to_string())]);
+        let client = Client::new(&server.url(), "secret", CONTACT).unwrap();
+
+        assert_eq!(
+            client.submit(2015, 1, 2, "1783").unwrap(),
+            Verdict::Incorrect {
+                hint: Some(Hint::TooLow)
+            }
+        );
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("POST /2015/day/1/answer "));
+        assert!(requests[0].ends_with("level=2&answer=1783"));
+    }
+
+    #[test]
+    fn test_fetch_input_error() {
+        let server = TestServer::start(vec![(400, "Please log in".to_string())]);
+        let client = Client::new(&server.url(), "expired", CONTACT).unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day02.txt");
+
+        let error = client.fetch_input(2015, 2, &path).unwrap_err();
+        assert!(matches!(
+            error,
+            ClientError::Status(StatusCode::BAD_REQUEST)
+        ));
+        assert!(!path.exists());
+    }
+}
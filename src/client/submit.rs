@@ -0,0 +1,207 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::time::Duration;
+
+/// Whether a wrong answer was too high or too low
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+}
+
+/// The server's response to a submitted answer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "verdict", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Incorrect {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        hint: Option<Hint>,
+    },
+    /// An answer was submitted too recently
+    RateLimited {
+        #[serde(skip)]
+        wait: Option<Duration>,
+    },
+    /// The part was already solved, or isn't unlocked yet
+    AlreadySolved,
+}
+
+/// The text of the `<article>` element holding the response message, with tags removed
+fn article_text(html: &str) -> &str {
+    let start = html
+        .find("<article")
+        .and_then(|i| html[i..].find('>').map(|j| i + j + 1));
+    match start {
+        Some(start) => {
+            let end = html[start..]
+                .find("</article>")
+                .map_or(html.len(), |i| start + i);
+            &html[start..end]
+        }
+        None => html,
+    }
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Parse a wait time such as `1m 5s` from "You have 1m 5s left to wait"
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+
+    let mut seconds = 0;
+    for token in text[start..end].split_whitespace() {
+        let (value, unit) = token.split_at(token.find(|c: char| !c.is_ascii_digit())?);
+        let value: u64 = value.parse().ok()?;
+        seconds += match unit {
+            "h" => value * 3600,
+            "m" => value * 60,
+            "s" => value,
+            _ => return None,
+        };
+    }
+    Some(Duration::from_secs(seconds))
+}
+
+/// Interpret the HTML page returned after submitting an answer
+pub fn parse_response(html: &str) -> Option<Verdict> {
+    let text = strip_tags(article_text(html));
+
+    if text.contains("That's the right answer") {
+        Some(Verdict::Correct)
+    } else if text.contains("That's not the right answer") {
+        let hint = if text.contains("your answer is too high") {
+            Some(Hint::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Some(Hint::TooLow)
+        } else {
+            None
+        };
+        Some(Verdict::Incorrect { hint })
+    } else if text.contains("You gave an answer too recently") {
+        Some(Verdict::RateLimited {
+            wait: parse_wait(&text),
+        })
+    } else if text.contains("You don't seem to be solving the right level") {
+        Some(Verdict::AlreadySolved)
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for Hint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Hint::TooHigh => write!(f, "too high"),
+            Hint::TooLow => write!(f, "too low"),
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✓ correct"),
+            Verdict::Incorrect { hint: Some(hint) } => write!(f, "✗ incorrect ({})", hint),
+            Verdict::Incorrect { hint: None } => write!(f, "✗ incorrect"),
+            Verdict::RateLimited { wait: Some(wait) } => {
+                let secs = wait.as_secs();
+                write!(
+                    f,
+                    "rate limited, try again in {}m {}s",
+                    secs / 60,
+                    secs % 60
+                )
+            }
+            Verdict::RateLimited { wait: None } => write!(f, "rate limited"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main>\n<article><p>{}</p></article>\n</main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page(
+                "That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer."
+            )),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high.  Please wait one minute before trying again."
+            )),
+            Some(Verdict::Incorrect {
+                hint: Some(Hint::TooHigh)
+            })
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Verdict::Incorrect {
+                hint: Some(Hint::TooLow)
+            })
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.")),
+            Some(Verdict::Incorrect { hint: None })
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait."
+            )),
+            Some(Verdict::RateLimited {
+                wait: Some(Duration::from_secs(65))
+            })
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Some(Verdict::AlreadySolved)
+        );
+        assert_eq!(
+            parse_response("<html>Puzzle inputs differ by user.</html>"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(
+            parse_wait("You have 34s left to wait."),
+            Some(Duration::from_secs(34))
+        );
+        assert_eq!(
+            parse_wait("You have 2m 0s left to wait."),
+            Some(Duration::from_secs(120))
+        );
+        assert_eq!(parse_wait("Please wait one minute."), None);
+    }
+}
@@ -0,0 +1,287 @@
+use super::ledger::{self, Ledger, Refusal};
+use super::{Client, ClientError, Verdict};
+use crate::days::Answer;
+use crate::runner::answers::Answers;
+use crate::runner::PartResult;
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Why an answer was not submitted
+#[derive(Debug)]
+pub enum SubmitError {
+    /// Multi-line answers and missing answers have to be given explicitly
+    Unsubmittable,
+    /// Earlier submissions in the ledger already rule the answer out
+    Refused(Refusal),
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+    Client(ClientError),
+}
+
+impl fmt::Display for SubmitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::Unsubmittable => {
+                write!(
+                    f,
+                    "multi-line and missing answers have to be passed explicitly"
+                )
+            }
+            SubmitError::Refused(refusal) => write!(f, "{}", refusal),
+            SubmitError::Read { path, source } => write!(f, "{}: {}", path.display(), source),
+            SubmitError::Client(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for SubmitError {}
+
+/// The verdict on a submitted answer
+#[derive(Debug)]
+pub struct Submitted {
+    pub verdict: Verdict,
+    /// For a part the server says is already solved, the correct answer recorded in the
+    /// answers file, if there is one
+    pub recorded: Option<Answer>,
+    /// Files the verdict or the correct answer could not be saved to
+    pub save_errors: Vec<(PathBuf, io::Error)>,
+}
+
+/// A submission attempt for one part of a run
+#[derive(Debug)]
+pub struct Outcome {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub result: Result<Submitted, SubmitError>,
+}
+
+impl Outcome {
+    /// Whether the answer is known to be correct after this attempt. A part solved
+    /// elsewhere only counts if the answers file records this answer as its solution.
+    pub fn is_accepted(&self) -> bool {
+        match &self.result {
+            Ok(Submitted {
+                verdict: Verdict::Correct,
+                ..
+            }) => true,
+            Ok(Submitted {
+                verdict: Verdict::AlreadySolved,
+                recorded,
+                ..
+            }) => recorded
+                .as_ref()
+                .is_some_and(|recorded| self.answer.matches(recorded)),
+            _ => false,
+        }
+    }
+
+    /// Whether the answer was left alone because it can't be submitted, rather than
+    /// submitted and rejected
+    pub fn is_skipped(&self) -> bool {
+        matches!(self.result, Err(SubmitError::Unsubmittable))
+    }
+}
+
+/// Submits answers, logging every verdict in the day's ledger and recording correct answers
+/// in the answers file
+pub struct Submitter<'a> {
+    pub client: &'a Client,
+    pub answers: &'a Path,
+    pub ledger_dir: &'a Path,
+}
+
+impl Submitter<'_> {
+    /// Submit an answer unless the ledger already rules it out
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &Answer,
+    ) -> Result<Submitted, SubmitError> {
+        if matches!(answer, Answer::Text(_) | Answer::None) {
+            return Err(SubmitError::Unsubmittable);
+        }
+
+        let ledger_path = ledger::path(self.ledger_dir, year, day);
+        let mut ledger = Ledger::load(&ledger_path).map_err(|source| SubmitError::Read {
+            path: ledger_path.clone(),
+            source,
+        })?;
+        ledger.check(part, answer).map_err(SubmitError::Refused)?;
+
+        let verdict = self
+            .client
+            .submit(year, day, part, &answer.to_string())
+            .map_err(SubmitError::Client)?;
+
+        // The server doesn't say whether the answer matches the one that solved the part
+        let recorded = match verdict {
+            Verdict::AlreadySolved => Answers::load(self.answers)
+                .ok()
+                .and_then(|answers| answers.get(year, day, part).cloned()),
+            _ => None,
+        };
+
+        let mut save_errors = Vec::new();
+        ledger.record(part, answer, &verdict);
+        if let Err(e) = ledger.save(&ledger_path) {
+            save_errors.push((ledger_path, e));
+        }
+        if verdict == Verdict::Correct {
+            let saved = Answers::load(self.answers).and_then(|mut answers| {
+                answers.set(year, day, part, answer);
+                answers.save(self.answers)
+            });
+            if let Err(e) = saved {
+                save_errors.push((self.answers.to_path_buf(), e));
+            }
+        }
+        Ok(Submitted {
+            verdict,
+            recorded,
+            save_errors,
+        })
+    }
+
+    /// Submit every answer of a run that isn't in the answers file yet, stopping once the
+    /// server asks to wait, as anything submitted after that would be refused as well.
+    /// Missing and multi-line answers are skipped; see [`Outcome::is_skipped`].
+    pub fn submit_new(&self, results: &[&PartResult]) -> io::Result<Vec<Outcome>> {
+        let answers = Answers::load(self.answers)?;
+        let mut outcomes = Vec::new();
+        for result in results {
+            let Ok(answer) = &result.answer else {
+                continue;
+            };
+            if answers.get(result.year, result.day, result.part).is_some() {
+                continue;
+            }
+            let outcome = Outcome {
+                year: result.year,
+                day: result.day,
+                part: result.part,
+                answer: answer.clone(),
+                result: self.submit(result.year, result.day, result.part, answer),
+            };
+            let rate_limited = matches!(
+                outcome.result,
+                Ok(Submitted {
+                    verdict: Verdict::RateLimited { .. },
+                    ..
+                })
+            );
+            outcomes.push(outcome);
+            if rate_limited {
+                break;
+            }
+        }
+        Ok(outcomes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::test_server::TestServer;
+    use std::fs;
+
+    const CORRECT: &str = "<article><p>That's the right answer!</p></article>";
+    const ALREADY_SOLVED: &str = "<article><p>You don't seem to be solving the right level.  \
+                                  Did you already complete it?</p></article>";
+
+    #[test]
+    fn test_submit() {
+        let server = TestServer::start(vec![(200, CORRECT.to_string())]);
+        let client = Client::new(&server.url(), "secret", "").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let answers = dir.path().join("answers.toml");
+        let submitter = Submitter {
+            client: &client,
+            answers: &answers,
+            ledger_dir: dir.path(),
+        };
+
+        let error = submitter.submit(2015, 1, 1, &Answer::None).unwrap_err();
+        assert!(matches!(error, SubmitError::Unsubmittable));
+
+        let submitted = submitter.submit(2015, 1, 1, &Answer::Int(74)).unwrap();
+        assert_eq!(submitted.verdict, Verdict::Correct);
+        assert!(submitted.save_errors.is_empty());
+        assert_eq!(
+            Answers::load(&answers).unwrap().get(2015, 1, 1),
+            Some(&Answer::Int(74))
+        );
+        assert!(fs::read_to_string(ledger::path(dir.path(), 2015, 1))
+            .unwrap()
+            .contains("\"correct\""));
+
+        // The ledger knows the part is solved, so nothing more is sent
+        let error = submitter.submit(2015, 1, 1, &Answer::Int(75)).unwrap_err();
+        assert!(matches!(
+            error,
+            SubmitError::Refused(Refusal::AlreadyCorrect(_))
+        ));
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_already_solved() {
+        let server = TestServer::start(vec![(200, ALREADY_SOLVED.to_string()); 3]);
+        let client = Client::new(&server.url(), "secret", "").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let answers = dir.path().join("answers.toml");
+        fs::write(&answers, "[2015.day01]\npart1 = 74\n").unwrap();
+        let submitter = Submitter {
+            client: &client,
+            answers: &answers,
+            ledger_dir: dir.path(),
+        };
+        let outcome = |day: u8, answer: i64| Outcome {
+            year: 2015,
+            day,
+            part: 1,
+            answer: Answer::Int(answer),
+            result: submitter.submit(2015, day, 1, &Answer::Int(answer)),
+        };
+
+        // Only an answer matching the recorded solution counts as accepted
+        let matching = outcome(1, 74);
+        assert_eq!(
+            matching.result.as_ref().unwrap().verdict,
+            Verdict::AlreadySolved
+        );
+        assert!(matching.is_accepted());
+        assert!(!outcome(1, 75).is_accepted());
+        assert!(!outcome(2, 74).is_accepted());
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn test_submit_new_skips_unsubmittable() {
+        let server = TestServer::start(vec![]);
+        let client = Client::new(&server.url(), "secret", "").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let submitter = Submitter {
+            client: &client,
+            answers: &dir.path().join("answers.toml"),
+            ledger_dir: dir.path(),
+        };
+
+        let results = [
+            PartResult::test(1, Answer::None),
+            PartResult::test(2, Answer::Text("#.\n.#".to_string())),
+        ];
+        let outcomes = submitter
+            .submit_new(&results.iter().collect::<Vec<_>>())
+            .unwrap();
+        assert_eq!(outcomes.len(), 2);
+        assert!(outcomes.iter().all(Outcome::is_skipped));
+        assert!(server.requests().is_empty());
+    }
+}
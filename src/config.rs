@@ -0,0 +1,153 @@
+use crate::client::{self, Client, ClientError};
+use crate::runner::report::Format;
+use crate::runner::DEFAULT_YEAR;
+use crate::utils::input::{self, Dirs};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+pub const DEFAULT_PATH: &str = "aoc.toml";
+
+/// Project defaults from `aoc.toml`; command line flags take precedence
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    /// Puzzle year used when no `--year` is given
+    pub year: u16,
+    pub input_dir: PathBuf,
+    pub example_dir: PathBuf,
+    /// Time budget per part when benchmarking, in seconds
+    pub bench_time: f64,
+    pub format: Format,
+    /// Number of days run at once
+    pub jobs: u16,
+    /// File the session cookie is read from when `AOC_SESSION` is not set
+    pub session_file: PathBuf,
+    /// Base URL of the Advent of Code website
+    pub base_url: String,
+    /// Repository URL or email address sent with every request, so the site can reach you
+    pub contact: String,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            year: DEFAULT_YEAR,
+            input_dir: PathBuf::from(input::DEFAULT_INPUT_DIR),
+            example_dir: PathBuf::from(input::DEFAULT_EXAMPLE_DIR),
+            bench_time: 1.0,
+            format: Format::Text,
+            jobs: 1,
+            session_file: PathBuf::from(".env"),
+            base_url: client::DEFAULT_BASE_URL.to_string(),
+            contact: String::new(),
+        }
+    }
+}
+
+impl Config {
+    /// Load the config file, or the defaults if it doesn't exist
+    pub fn load(path: &Path) -> io::Result<Config> {
+        let invalid = |message: String| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), message),
+            )
+        };
+        let config: Config = match fs::read_to_string(path) {
+            Ok(contents) => toml::from_str(&contents).map_err(|e| invalid(e.to_string()))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Config::default()),
+            Err(e) => return Err(e),
+        };
+
+        if !config.bench_time.is_finite() || config.bench_time <= 0.0 {
+            return Err(invalid(format!(
+                "bench_time must be a positive number of seconds, got {}",
+                config.bench_time
+            )));
+        }
+        if config.jobs == 0 {
+            return Err(invalid("jobs must be at least 1".to_string()));
+        }
+        Ok(config)
+    }
+
+    pub fn bench_time(&self) -> Duration {
+        Duration::from_secs_f64(self.bench_time)
+    }
+
+    /// The input and example directories, with the input directory from `AOC_INPUT_DIR`
+    /// if set
+    pub fn dirs(&self) -> Dirs {
+        Dirs::new(&self.input_dir, &self.example_dir)
+    }
+
+    /// The configuration that applies, with the input directory from `AOC_INPUT_DIR` if set
+    pub fn effective(&self) -> Config {
+        Config {
+            input_dir: self.dirs().inputs,
+            ..self.clone()
+        }
+    }
+
+    /// A client for the website at `base_url`, or the configured one, using the session
+    /// from the environment or the session file
+    pub fn connect(&self, base_url: Option<&str>) -> Result<Client, ClientError> {
+        let session = client::load_session(&self.session_file)?;
+        Client::new(base_url.unwrap_or(&self.base_url), &session, &self.contact)
+    }
+
+    /// The configuration as it would be written in `aoc.toml`
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).expect("Failed to serialize config")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(contents: &str) -> io::Result<Config> {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_PATH);
+        fs::write(&path, contents).unwrap();
+        Config::load(&path)
+    }
+
+    #[test]
+    fn test_load() {
+        let dir = tempfile::tempdir().unwrap();
+        let missing = Config::load(&dir.path().join(DEFAULT_PATH)).unwrap();
+        assert_eq!(missing, Config::default());
+
+        let config = load("year = 2016\nformat = \"json\"\nbench_time = 0.5\n").unwrap();
+        assert_eq!(
+            config,
+            Config {
+                year: 2016,
+                format: Format::Json,
+                bench_time: 0.5,
+                ..Config::default()
+            }
+        );
+        assert_eq!(config.bench_time(), Duration::from_millis(500));
+
+        let roundtrip: Config = toml::from_str(&config.to_toml()).unwrap();
+        assert_eq!(roundtrip, config);
+    }
+
+    #[test]
+    fn test_load_invalid() {
+        let error = load("yaer = 2016\n").unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+        assert!(error.to_string().contains("unknown field `yaer`"));
+
+        let error = load("bench_time = 0\n").unwrap_err();
+        assert!(error.to_string().contains("bench_time must be a positive"));
+
+        let error = load("jobs = 0\n").unwrap_err();
+        assert!(error.to_string().contains("jobs must be at least 1"));
+    }
+}
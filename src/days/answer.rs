@@ -0,0 +1,188 @@
+use serde::de::{self, Deserialize, Deserializer, Visitor};
+use serde::ser::{Serialize, Serializer};
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+/// The answer to a puzzle part
+#[derive(Debug, Clone, Eq)]
+pub enum Answer {
+    Int(i64),
+    Unsigned(u64),
+    Str(String),
+    /// Multi-line output, such as letters drawn on a grid
+    Text(String),
+    /// The puzzle has no answer for this input
+    None,
+}
+
+impl Answer {
+    /// Multi-line text answer
+    pub fn text(text: impl Into<String>) -> Self {
+        Answer::Text(text.into())
+    }
+
+    /// The numeric value, if this is a number
+    pub fn as_i128(&self) -> Option<i128> {
+        match self {
+            Answer::Int(n) => Some(*n as i128),
+            Answer::Unsigned(n) => Some(*n as i128),
+            _ => None,
+        }
+    }
+
+    /// Whether this matches an expected answer. Numbers compare by value, anything
+    /// else by its text, so answers recorded as strings still match numeric results.
+    pub fn matches(&self, expected: &Answer) -> bool {
+        match (self.as_i128(), expected.as_i128()) {
+            (Some(a), Some(b)) => a == b,
+            _ => self.to_string().trim() == expected.to_string().trim(),
+        }
+    }
+}
+
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Str(a), Answer::Str(b)) | (Answer::Text(a), Answer::Text(b)) => a == b,
+            (Answer::None, Answer::None) => true,
+            _ => self.as_i128().is_some() && self.as_i128() == other.as_i128(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Int(n) => write!(f, "{}", n),
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Str(s) | Answer::Text(s) => write!(f, "{}", s),
+            Answer::None => write!(f, "no answer"),
+        }
+    }
+}
+
+macro_rules! impl_from_int {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::$variant(n as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_int!(Int, i64, i8, i16, i32, i64, isize);
+impl_from_int!(Unsigned, u64, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Str(s)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        Answer::Str(s.to_string())
+    }
+}
+
+/// Reads an answer typed by hand, such as one given on the command line
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        Ok(s.parse::<i64>()
+            .map(Answer::Int)
+            .or_else(|_| s.parse::<u64>().map(Answer::Unsigned))
+            .unwrap_or_else(|_| Answer::from(s)))
+    }
+}
+
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(n) => serializer.serialize_i64(*n),
+            Answer::Unsigned(n) => serializer.serialize_u64(*n),
+            Answer::Str(s) | Answer::Text(s) => serializer.serialize_str(s),
+            Answer::None => serializer.serialize_none(),
+        }
+    }
+}
+
+struct AnswerVisitor;
+
+impl Visitor<'_> for AnswerVisitor {
+    type Value = Answer;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "an integer or a string")
+    }
+
+    fn visit_i64<E: de::Error>(self, n: i64) -> Result<Answer, E> {
+        Ok(Answer::Int(n))
+    }
+
+    fn visit_u64<E: de::Error>(self, n: u64) -> Result<Answer, E> {
+        Ok(Answer::Unsigned(n))
+    }
+
+    fn visit_str<E: de::Error>(self, s: &str) -> Result<Answer, E> {
+        if s.contains('\n') {
+            Ok(Answer::text(s))
+        } else {
+            Ok(Answer::from(s))
+        }
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Answer, E> {
+        Ok(Answer::None)
+    }
+}
+
+impl<'de> Deserialize<'de> for Answer {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(AnswerVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_numbers_compare_by_value() {
+        assert_eq!(Answer::Int(42), Answer::Unsigned(42));
+        assert_ne!(Answer::Int(-1), Answer::Unsigned(u64::MAX));
+        assert_ne!(Answer::Int(42), Answer::from("42"));
+        assert!(Answer::Int(42).matches(&Answer::from("42")));
+        assert!(!Answer::None.matches(&Answer::Int(0)));
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("-232".parse(), Ok(Answer::Int(-232)));
+        assert_eq!(
+            " 18446744073709551615\n".parse(),
+            Ok(Answer::Unsigned(u64::MAX))
+        );
+        assert_eq!("abc".parse(), Ok(Answer::from("abc")));
+    }
+
+    #[test]
+    fn test_json() {
+        let answers = vec![Answer::Int(-3), Answer::from("abc"), Answer::None];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(json, r#"[-3,"abc",null]"#);
+
+        let parsed: Vec<Answer> = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, answers);
+    }
+}
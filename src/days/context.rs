@@ -0,0 +1,225 @@
+use super::SolveError;
+use std::cell::{Cell, RefCell};
+use std::fmt;
+use std::io::{self, Write};
+use std::time::{Duration, Instant};
+
+/// Importance of a log message
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Warn,
+    Info,
+    Debug,
+}
+
+/// What a part reports on stderr while it runs; nothing by default
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Output {
+    /// The most detailed log level shown, or `None` to show no logs and keep no values
+    pub level: Option<Level>,
+    /// Show progress counters
+    pub progress: bool,
+}
+
+impl Output {
+    /// Output for `-v` given `verbosity` times
+    pub fn verbose(verbosity: u8, progress: bool) -> Self {
+        let level = match verbosity {
+            0 => None,
+            1 => Some(Level::Info),
+            _ => Some(Level::Debug),
+        };
+        Output { level, progress }
+    }
+}
+
+/// How often progress is redrawn
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(200);
+
+/// Runtime state handed to each part while it is solved: the time limit, logging,
+/// progress counters and named debug values
+#[derive(Debug)]
+pub struct Context {
+    timeout: Option<Duration>,
+    started: Instant,
+    deadline: Option<Instant>,
+    output: Output,
+    /// Prefix for everything printed, e.g. `day 4 part 1`
+    label: String,
+    last_progress: Cell<Option<Instant>>,
+    values: RefCell<Vec<(String, String)>>,
+}
+
+impl Default for Context {
+    fn default() -> Self {
+        Context::new(None)
+    }
+}
+
+impl Context {
+    /// A context for a part that has to finish within `timeout`, counting from now
+    pub fn new(timeout: Option<Duration>) -> Self {
+        let started = Instant::now();
+        Context {
+            timeout,
+            started,
+            deadline: timeout.map(|timeout| started + timeout),
+            output: Output::default(),
+            label: String::new(),
+            last_progress: Cell::new(None),
+            values: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// Report logs and progress as configured, prefixed with `label`
+    pub fn with_output(mut self, output: Output, label: impl Into<String>) -> Self {
+        self.output = output;
+        self.label = label.into();
+        self
+    }
+
+    /// Whether a long-running part should give up
+    pub fn should_stop(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+
+    /// Fail with a timed-out error once the part should stop, for use with `?`
+    pub fn check(&self) -> Result<(), SolveError> {
+        match self.timeout {
+            Some(timeout) if self.should_stop() => Err(SolveError::timed_out(timeout)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Whether messages at `level` are shown, to skip expensive work for hidden messages
+    pub fn enabled(&self, level: Level) -> bool {
+        self.output.level.is_some_and(|shown| level <= shown)
+    }
+
+    /// Log a message, e.g. `ctx.log(Level::Info, format_args!("{} gates", n))`
+    pub fn log(&self, level: Level, message: impl fmt::Display) {
+        if self.enabled(level) {
+            self.clear_progress();
+            let level = match level {
+                Level::Warn => "warn",
+                Level::Info => "info",
+                Level::Debug => "debug",
+            };
+            eprintln!("[{}] {}: {}", self.label, level, message);
+        }
+    }
+
+    pub fn warn(&self, message: impl fmt::Display) {
+        self.log(Level::Warn, message);
+    }
+
+    pub fn info(&self, message: impl fmt::Display) {
+        self.log(Level::Info, message);
+    }
+
+    pub fn debug(&self, message: impl fmt::Display) {
+        self.log(Level::Debug, message);
+    }
+
+    /// Report how many `unit`s of work are done, shown with the rate per second.
+    /// Cheap enough to call often; the display is only redrawn a few times per second.
+    pub fn progress(&self, unit: &str, done: u64) {
+        if !self.output.progress {
+            return;
+        }
+        let now = Instant::now();
+        if self
+            .last_progress
+            .get()
+            .is_some_and(|last| now - last < PROGRESS_INTERVAL)
+        {
+            return;
+        }
+        self.last_progress.set(Some(now));
+
+        let rate = done as f64 / (now - self.started).as_secs_f64().max(1e-9);
+        eprint!(
+            "\r\x1b[K[{}] {} {}, {}/s",
+            self.label,
+            format_count(done as f64),
+            unit,
+            format_count(rate)
+        );
+        let _ = io::stderr().flush();
+    }
+
+    /// Erase the progress line, if one was drawn
+    pub fn clear_progress(&self) {
+        if self.last_progress.take().is_some() {
+            eprint!("\r\x1b[K");
+        }
+    }
+
+    /// Record a named value worth seeing when debugging, reported with the part's result
+    pub fn value(&self, name: &str, value: impl fmt::Display) {
+        if self.output.level.is_some() {
+            self.values
+                .borrow_mut()
+                .push((name.to_string(), value.to_string()));
+        }
+    }
+
+    /// The recorded debug values, in the order they were recorded
+    pub fn into_values(self) -> Vec<(String, String)> {
+        self.clear_progress();
+        self.values.into_inner()
+    }
+}
+
+/// Format a count with a metric suffix, e.g. `4.1M`
+fn format_count(n: f64) -> String {
+    match n {
+        n if n >= 1e9 => format!("{:.2}G", n / 1e9),
+        n if n >= 1e6 => format!("{:.2}M", n / 1e6),
+        n if n >= 1e3 => format!("{:.1}k", n / 1e3),
+        n => format!("{:.0}", n),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check() {
+        assert!(Context::default().check().is_ok());
+        assert!(Context::new(Some(Duration::from_secs(60))).check().is_ok());
+
+        let error = Context::new(Some(Duration::ZERO)).check().unwrap_err();
+        assert!(error.timed_out);
+        assert_eq!(error.to_string(), "timed out after 0ns");
+    }
+
+    #[test]
+    fn test_values() {
+        let ctx = Context::default();
+        ctx.value("ignored", 1);
+        assert!(ctx.into_values().is_empty());
+
+        let ctx = Context::default().with_output(Output::verbose(1, false), "day 1 part 1");
+        assert!(ctx.enabled(Level::Info));
+        assert!(!ctx.enabled(Level::Debug));
+        ctx.value("floor", -3);
+        ctx.value("digest", format_args!("{:x}", 255));
+        assert_eq!(
+            ctx.into_values(),
+            vec![
+                ("floor".to_string(), "-3".to_string()),
+                ("digest".to_string(), "ff".to_string())
+            ]
+        );
+    }
+
+    #[test]
+    fn test_format_count() {
+        assert_eq!(format_count(999.0), "999");
+        assert_eq!(format_count(4096.0), "4.1k");
+        assert_eq!(format_count(4_100_000.0), "4.10M");
+    }
+}
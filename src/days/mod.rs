@@ -1,12 +1,207 @@
-pub mod day01;
-
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
+/// Register a day's solution with its metadata, e.g.
+/// `solution!(Day01, day: 1, title: "Not Quite Lisp", tags: ["simulation"]);`
+///
+/// Whitespace-sensitive days can add `normalize: LineEndings` to keep trailing whitespace.
+/// Expands to the `REGISTRATION` constant that [`days!`] collects; the year comes from
+/// the `YEAR` constant of the year module the day belongs to.
+#[macro_export]
+macro_rules! solution {
+    (
+        $solution:ident,
+        day: $day:literal,
+        title: $title:literal,
+        tags: [$($tag:literal),* $(,)?]
+        $(, normalize: $normalize:ident)?
+        $(,)?
+    ) => {
+        pub const REGISTRATION: $crate::days::Registration = $crate::days::Registration {
+            year: super::YEAR,
+            day: $day,
+            title: $title,
+            tags: &[$($tag),*],
+            normalize: {
+                #[allow(unused_variables)]
+                let normalize = $crate::utils::input::Normalize::Full;
+                $(let normalize = $crate::utils::input::Normalize::$normalize;)?
+                normalize
+            },
+            solution: &$solution,
+        };
+    };
+}
+
+/// Declare the day modules of a year and collect their registrations into its `REGISTRY`
+#[macro_export]
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// Every registered day of the year, in the order they are listed
+        pub static REGISTRY: &[$crate::days::Registration] = &[$($module::REGISTRATION),*];
+    };
+}
+
+/// Declare the year modules and collect their registries into [`YEARS`]
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        /// The registered days of every year, in the order the years are listed
+        pub static YEARS: &[&[$crate::days::Registration]] = &[$($module::REGISTRY),*];
+    };
+}
+
+years! {
+    y2015,
+}
+
+mod answer;
+mod context;
+
+use crate::utils::input::Normalize;
+pub use answer::Answer;
+pub use context::{Context, Level, Output};
+use std::any::Any;
+use std::fmt;
+use std::time::Duration;
+
+/// A day's solution and its metadata
+pub struct Registration {
+    pub year: u16,
+    pub day: u8,
+    pub title: &'static str,
+    pub tags: &'static [&'static str],
+    /// How the input is cleaned up before parsing
+    pub normalize: Normalize,
+    pub solution: &'static dyn DynSolution,
+}
+
+/// Every registered day of every year
+pub fn registrations() -> impl Iterator<Item = &'static Registration> {
+    YEARS.iter().flat_map(|year| year.iter())
+}
+
+/// The registered days of a year, if it has any
+pub fn year(year: u16) -> Option<&'static [Registration]> {
+    YEARS
+        .iter()
+        .copied()
+        .find(|days| days.first().is_some_and(|first| first.year == year))
+}
+
+/// Look up the registration for a day of a year
+pub fn find(year: u16, day: u8) -> Option<&'static Registration> {
+    registrations().find(|registration| registration.year == year && registration.day == day)
+}
+
+/// An error raised while solving, usually caused by malformed input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub day: Option<u8>,
+    /// Line number in the input, starting at 1
+    pub line: Option<usize>,
+    /// The offending input text
+    pub text: Option<String>,
+    pub reason: String,
+    /// The part gave up because it ran out of time
+    pub timed_out: bool,
+}
+
+impl SolveError {
+    pub fn new(reason: impl Into<String>) -> Self {
+        SolveError {
+            day: None,
+            line: None,
+            text: None,
+            reason: reason.into(),
+            timed_out: false,
+        }
+    }
+
+    /// A part that stopped because it exceeded its time limit
+    pub fn timed_out(timeout: Duration) -> Self {
+        SolveError {
+            timed_out: true,
+            ..SolveError::new(format!("timed out after {:?}", timeout))
+        }
+    }
+
+    /// An error caused by a specific input line (numbered from 1)
+    pub fn at_line(line: usize, text: &str, reason: impl Into<String>) -> Self {
+        SolveError {
+            line: Some(line),
+            text: Some(text.to_string()),
+            ..SolveError::new(reason)
+        }
+    }
+
+    /// Attach the day, unless the error already has one
+    pub fn for_day(mut self, day: u8) -> Self {
+        self.day.get_or_insert(day);
+        self
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.day, self.line) {
+            (Some(day), Some(line)) => write!(f, "day {}, line {}: ", day, line)?,
+            (Some(day), None) => write!(f, "day {}: ", day)?,
+            (None, Some(line)) => write!(f, "line {}: ", line)?,
+            (None, None) => {}
+        }
+        write!(f, "{}", self.reason)?;
+        if let Some(text) = &self.text {
+            write!(f, " in {:?}", text)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for SolveError {}
+
 pub trait Solution {
-    fn part1(&self, input: &str) -> String;
-    fn part2(&self, input: &str) -> String;
+    /// The input after parsing, shared by both parts
+    type Parsed;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError>;
+    fn part1(&self, input: &Self::Parsed, ctx: &Context) -> Result<Answer, SolveError>;
+    fn part2(&self, input: &Self::Parsed, ctx: &Context) -> Result<Answer, SolveError>;
+
+    /// Parse the input and solve part 1
+    fn solve_part1(&self, input: &str) -> Result<Answer, SolveError> {
+        self.part1(&self.parse(input)?, &Context::default())
+    }
+
+    /// Parse the input and solve part 2
+    fn solve_part2(&self, input: &str) -> Result<Answer, SolveError> {
+        self.part2(&self.parse(input)?, &Context::default())
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different parsed types can be run alike
+pub trait DynSolution: Sync {
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, SolveError>;
+    fn solve(&self, part: u8, parsed: &dyn Any, ctx: &Context) -> Result<Answer, SolveError>;
+}
+
+impl<S> DynSolution for S
+where
+    S: Solution + Sync,
+    S::Parsed: 'static,
+{
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, SolveError> {
+        Ok(Box::new(self.parse(input)?))
+    }
+
+    fn solve(&self, part: u8, parsed: &dyn Any, ctx: &Context) -> Result<Answer, SolveError> {
+        let parsed = parsed
+            .downcast_ref::<S::Parsed>()
+            .expect("Parsed input belongs to a different solution");
+        match part {
+            1 => self.part1(parsed, ctx),
+            2 => self.part2(parsed, ctx),
+            _ => Err(SolveError::new(format!("there is no part {}", part))),
+        }
+    }
 }
@@ -0,0 +1,54 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+
+pub struct Day01;
+
+solution!(
+    Day01,
+    day: 1,
+    title: "Not Quite Lisp",
+    tags: ["simulation"],
+);
+
+impl Solution for Day01 {
+    /// The floor change for each character
+    type Parsed = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input
+            .chars()
+            .map(|c| match c {
+                '(' => 1,
+                ')' => -1,
+                _ => 0,
+            })
+            .collect())
+    }
+
+    fn part1(&self, steps: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let floor: i32 = steps.iter().sum();
+        Ok(floor.into())
+    }
+
+    fn part2(&self, steps: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let mut floor = 0;
+        for (i, step) in steps.iter().enumerate() {
+            floor += step;
+            if floor == -1 {
+                return Ok((i + 1).into());
+            }
+        }
+        Ok(Answer::None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part2() {
+        // The puzzle examples live in examples/2015/day01; this one never reaches the basement
+        let solution = Day01;
+        assert_eq!(solution.solve_part2("(()").unwrap(), Answer::None);
+    }
+}
@@ -0,0 +1,104 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+
+pub struct Day02;
+
+solution!(
+    Day02,
+    day: 2,
+    title: "I Was Told There Would Be No Math",
+    tags: ["math", "parsing"],
+);
+
+fn parse_dimensions(line: &str) -> Result<(u32, u32, u32), String> {
+    let parts: Vec<&str> = line.split('x').collect();
+    let [l, w, h] = parts[..] else {
+        return Err(format!("expected LxWxH, found {} dimensions", parts.len()));
+    };
+    let parse = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| format!("invalid dimension '{}'", s))
+    };
+    Ok((parse(l)?, parse(w)?, parse(h)?))
+}
+
+/// Parse every non-empty line into the dimensions of a present
+fn parse_presents(input: &str) -> Result<Vec<(u32, u32, u32)>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_dimensions(line).map_err(|e| SolveError::at_line(i + 1, line, e)))
+        .collect()
+}
+
+fn calculate_wrapping_paper(l: u32, w: u32, h: u32) -> u32 {
+    let side1 = l * w;
+    let side2 = w * h;
+    let side3 = h * l;
+    let smallest = side1.min(side2).min(side3);
+    2 * side1 + 2 * side2 + 2 * side3 + smallest
+}
+
+fn calculate_ribbon(l: u32, w: u32, h: u32) -> u32 {
+    let mut sides = [l, w, h];
+    sides.sort_unstable();
+    let wrap = 2 * (sides[0] + sides[1]);
+    let bow = l * w * h;
+    wrap + bow
+}
+
+impl Solution for Day02 {
+    type Parsed = Vec<(u32, u32, u32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        parse_presents(input)
+    }
+
+    fn part1(&self, presents: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let total: u32 = presents
+            .iter()
+            .map(|&(l, w, h)| calculate_wrapping_paper(l, w, h))
+            .sum();
+        Ok(total.into())
+    }
+
+    fn part2(&self, presents: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let total: u32 = presents
+            .iter()
+            .map(|&(l, w, h)| calculate_ribbon(l, w, h))
+            .sum();
+        Ok(total.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let solution = Day02;
+        assert_eq!(solution.solve_part1("2x3x4").unwrap(), 58.into());
+        assert_eq!(solution.solve_part1("1x1x10").unwrap(), 43.into());
+        assert_eq!(solution.solve_part1("2x3x4\n1x1x10").unwrap(), 101.into());
+    }
+
+    #[test]
+    fn test_part2() {
+        let solution = Day02;
+        assert_eq!(solution.solve_part2("2x3x4").unwrap(), 34.into());
+        assert_eq!(solution.solve_part2("1x1x10").unwrap(), 14.into());
+        assert_eq!(solution.solve_part2("2x3x4\n1x1x10").unwrap(), 48.into());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let solution = Day02;
+        let error = solution.solve_part1("2x3x4\n1x1").unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.text.as_deref(), Some("1x1"));
+
+        let error = solution.solve_part2("2x3xfour").unwrap_err();
+        assert_eq!(error.reason, "invalid dimension 'four'");
+    }
+}
@@ -0,0 +1,78 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+use crate::utils::navigation;
+use std::collections::HashSet;
+
+pub struct Day03;
+
+solution!(
+    Day03,
+    day: 3,
+    title: "Perfectly Spherical Houses in a Vacuum",
+    tags: ["grid", "simulation"],
+);
+
+impl Solution for Day03 {
+    type Parsed = Vec<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input.chars().collect())
+    }
+
+    fn part1(&self, directions: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let mut visited = HashSet::new();
+        let mut x = 0;
+        let mut y = 0;
+
+        visited.insert((x, y));
+
+        for &c in directions {
+            navigation::move_in_direction(&mut x, &mut y, c);
+            visited.insert((x, y));
+        }
+
+        Ok(visited.len().into())
+    }
+
+    fn part2(&self, directions: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let mut visited = HashSet::new();
+        let mut santa_x = 0;
+        let mut santa_y = 0;
+        let mut robo_x = 0;
+        let mut robo_y = 0;
+
+        visited.insert((0, 0));
+
+        for (i, &c) in directions.iter().enumerate() {
+            if i % 2 == 0 {
+                navigation::move_in_direction(&mut santa_x, &mut santa_y, c);
+                visited.insert((santa_x, santa_y));
+            } else {
+                navigation::move_in_direction(&mut robo_x, &mut robo_y, c);
+                visited.insert((robo_x, robo_y));
+            }
+        }
+
+        Ok(visited.len().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let solution = Day03;
+        assert_eq!(solution.solve_part1(">").unwrap(), 2.into());
+        assert_eq!(solution.solve_part1("^>v<").unwrap(), 4.into());
+        assert_eq!(solution.solve_part1("^v^v^v^v^v").unwrap(), 2.into());
+    }
+
+    #[test]
+    fn test_part2() {
+        let solution = Day03;
+        assert_eq!(solution.solve_part2("^v").unwrap(), 3.into());
+        assert_eq!(solution.solve_part2("^>v<").unwrap(), 3.into());
+        assert_eq!(solution.solve_part2("^v^v^v^v^v").unwrap(), 11.into());
+    }
+}
@@ -0,0 +1,83 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+
+pub struct Day04;
+
+solution!(
+    Day04,
+    day: 4,
+    title: "The Ideal Stocking Stuffer",
+    tags: ["hashing", "brute-force"],
+);
+
+use md5;
+
+/// How many hashes to try between checks for a timeout and progress updates
+const CHECK_INTERVAL: u64 = 4096;
+
+impl Solution for Day04 {
+    /// The secret key
+    type Parsed = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input.trim().to_string())
+    }
+
+    fn part1(&self, key: &Self::Parsed, ctx: &Context) -> Result<Answer, SolveError> {
+        let mut n: u64 = 1;
+
+        loop {
+            let text = format!("{}{}", key, n);
+            let digest = md5::compute(text);
+
+            if digest[0] == 0 && digest[1] == 0 && (digest[2] & 0xF0) == 0 {
+                ctx.info(format_args!("found {}{}", key, n));
+                ctx.value("digest", format_args!("{:x}", digest));
+                return Ok(n.into());
+            }
+
+            n += 1;
+            if n.is_multiple_of(CHECK_INTERVAL) {
+                ctx.progress("hashes", n);
+                ctx.check()?;
+            }
+        }
+    }
+
+    fn part2(&self, key: &Self::Parsed, ctx: &Context) -> Result<Answer, SolveError> {
+        let mut n: u64 = 1;
+
+        loop {
+            let text = format!("{}{}", key, n);
+            let digest = md5::compute(text);
+
+            if digest[0] == 0 && digest[1] == 0 && digest[2] == 0 {
+                ctx.info(format_args!("found {}{}", key, n));
+                ctx.value("digest", format_args!("{:x}", digest));
+                return Ok(n.into());
+            }
+
+            n += 1;
+            if n.is_multiple_of(CHECK_INTERVAL) {
+                ctx.progress("hashes", n);
+                ctx.check()?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let solution = Day04;
+        assert_eq!(solution.solve_part1("abcdef").unwrap(), 609043.into());
+        assert_eq!(solution.solve_part1("pqrstuv").unwrap(), 1048970.into());
+    }
+
+    #[test]
+    fn test_part2() {
+        // let solution = Day04;
+    }
+}
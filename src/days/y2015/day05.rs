@@ -0,0 +1,99 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+
+pub struct Day05;
+
+solution!(
+    Day05,
+    day: 5,
+    title: "Doesn't He Have Intern-Elves For This?",
+    tags: ["strings"],
+);
+
+fn is_nice_part1(s: &str) -> bool {
+    let vowel_count = s
+        .chars()
+        .filter(|c| matches!(c, 'a' | 'e' | 'i' | 'o' | 'u'))
+        .count();
+    if vowel_count < 3 {
+        return false;
+    }
+
+    let has_double = s.as_bytes().windows(2).any(|w| w[0] == w[1]);
+    if !has_double {
+        return false;
+    }
+
+    let forbidden = ["ab", "cd", "pq", "xy"];
+    if forbidden.iter().any(|&f| s.contains(f)) {
+        return false;
+    }
+
+    true
+}
+
+fn is_nice_part2(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    let has_pair = (0..bytes.len().saturating_sub(1)).any(|i| {
+        let pair = &bytes[i..i + 2];
+        bytes[i + 2..].windows(2).any(|w| w == pair)
+    });
+
+    if !has_pair {
+        return false;
+    }
+
+    let has_repeat_with_gap = bytes.windows(3).any(|w| w[0] == w[2]);
+    if !has_repeat_with_gap {
+        return false;
+    }
+
+    true
+}
+
+impl Solution for Day05 {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(&self, strings: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let count = strings.iter().filter(|s| is_nice_part1(s)).count();
+        Ok(count.into())
+    }
+
+    fn part2(&self, strings: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let count = strings.iter().filter(|s| is_nice_part2(s)).count();
+        Ok(count.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let solution = Day05;
+        assert_eq!(solution.solve_part1("ugknbfddgicrmopn").unwrap(), 1.into());
+        assert_eq!(solution.solve_part1("aaa").unwrap(), 1.into());
+        assert_eq!(solution.solve_part1("jchzalrnumimnmhp").unwrap(), 0.into());
+        assert_eq!(solution.solve_part1("haegwjzuvuyypxyu").unwrap(), 0.into());
+        assert_eq!(solution.solve_part1("dvszwmarrgswjxmb").unwrap(), 0.into());
+        assert_eq!(
+            solution
+                .solve_part1("ugknbfddgicrmopn\naaa\njchzalrnumimnmhp")
+                .unwrap(),
+            2.into()
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let solution = Day05;
+        assert_eq!(solution.solve_part2("qjhvhtzxzqqjkmpb").unwrap(), 1.into());
+        assert_eq!(solution.solve_part2("xxyxx").unwrap(), 1.into());
+        assert_eq!(solution.solve_part2("uurcxstgmygtbstg").unwrap(), 0.into());
+        assert_eq!(solution.solve_part2("ieodomkazucvgmuy").unwrap(), 0.into());
+    }
+}
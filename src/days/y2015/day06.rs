@@ -0,0 +1,172 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+
+pub struct Day06;
+
+solution!(
+    Day06,
+    day: 6,
+    title: "Probably a Fire Hazard",
+    tags: ["grid", "parsing"],
+);
+
+enum Instruction {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+pub struct Command {
+    instruction: Instruction,
+    x1: usize,
+    y1: usize,
+    x2: usize,
+    y2: usize,
+}
+
+const GRID_SIZE: usize = 1000;
+
+fn parse_point(s: &str) -> Result<(usize, usize), String> {
+    let point = || format!("invalid coordinate pair '{}'", s);
+    let (x, y) = s.split_once(',').ok_or_else(point)?;
+    let x: usize = x.parse().map_err(|_| point())?;
+    let y: usize = y.parse().map_err(|_| point())?;
+    if x >= GRID_SIZE || y >= GRID_SIZE {
+        return Err(format!("coordinate pair '{}' is outside the grid", s));
+    }
+    Ok((x, y))
+}
+
+fn parse_command(line: &str) -> Result<Command, String> {
+    let (instruction, rest) = if let Some(rest) = line.strip_prefix("turn on ") {
+        (Instruction::TurnOn, rest)
+    } else if let Some(rest) = line.strip_prefix("turn off ") {
+        (Instruction::TurnOff, rest)
+    } else if let Some(rest) = line.strip_prefix("toggle ") {
+        (Instruction::Toggle, rest)
+    } else {
+        return Err("expected 'turn on', 'turn off' or 'toggle'".to_string());
+    };
+
+    let (from, to) = rest
+        .split_once(" through ")
+        .ok_or("expected '<x,y> through <x,y>'")?;
+    let (x1, y1) = parse_point(from.trim())?;
+    let (x2, y2) = parse_point(to.trim())?;
+    if x1 > x2 || y1 > y2 {
+        return Err("the first corner must not be after the second".to_string());
+    }
+
+    Ok(Command {
+        instruction,
+        x1,
+        y1,
+        x2,
+        y2,
+    })
+}
+
+fn parse_commands(input: &str) -> Result<Vec<Command>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty())
+        .map(|(i, line)| parse_command(line).map_err(|e| SolveError::at_line(i + 1, line, e)))
+        .collect()
+}
+
+impl Solution for Day06 {
+    type Parsed = Vec<Command>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        parse_commands(input)
+    }
+
+    fn part1(&self, commands: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let mut grid = vec![vec![false; GRID_SIZE]; GRID_SIZE];
+
+        for cmd in commands {
+            for row in &mut grid[cmd.x1..=cmd.x2] {
+                for light in &mut row[cmd.y1..=cmd.y2] {
+                    match cmd.instruction {
+                        Instruction::TurnOn => *light = true,
+                        Instruction::TurnOff => *light = false,
+                        Instruction::Toggle => *light = !*light,
+                    }
+                }
+            }
+        }
+
+        let count = grid.iter().flatten().filter(|&&light| light).count();
+        Ok(count.into())
+    }
+
+    fn part2(&self, commands: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        let mut grid = vec![vec![0i32; GRID_SIZE]; GRID_SIZE];
+
+        for cmd in commands {
+            for row in &mut grid[cmd.x1..=cmd.x2] {
+                for light in &mut row[cmd.y1..=cmd.y2] {
+                    match cmd.instruction {
+                        Instruction::TurnOn => *light += 1,
+                        Instruction::TurnOff => *light = (*light - 1).max(0),
+                        Instruction::Toggle => *light += 2,
+                    }
+                }
+            }
+        }
+
+        let total_brightness: i32 = grid.iter().flatten().sum();
+        Ok(total_brightness.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let solution = Day06;
+        assert_eq!(
+            solution.solve_part1("turn on 0,0 through 999,999").unwrap(),
+            1000000.into()
+        );
+        assert_eq!(
+            solution.solve_part1("toggle 0,0 through 999,0").unwrap(),
+            1000.into()
+        );
+        assert_eq!(
+            solution
+                .solve_part1("turn on 0,0 through 999,999\nturn off 499,499 through 500,500")
+                .unwrap(),
+            999996.into()
+        );
+    }
+
+    #[test]
+    fn test_part2() {
+        let solution = Day06;
+        assert_eq!(
+            solution.solve_part2("turn on 0,0 through 0,0").unwrap(),
+            1.into()
+        );
+        assert_eq!(
+            solution.solve_part2("toggle 0,0 through 999,999").unwrap(),
+            2000000.into()
+        );
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let solution = Day06;
+        let error = solution
+            .solve_part1("turn on 0,0 through 9,9\nturn up 0,0 through 9,9")
+            .unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.text.as_deref(), Some("turn up 0,0 through 9,9"));
+
+        assert!(solution.solve_part2("toggle 0,0 through 1000,5").is_err());
+        assert!(solution.solve_part2("toggle 5,5 through 0,0").is_err());
+        assert!(solution.solve_part2("toggle 0,0").is_err());
+    }
+}
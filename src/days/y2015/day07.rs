@@ -0,0 +1,215 @@
+use crate::days::{Answer, Context, Solution, SolveError};
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
+
+pub struct Day07;
+
+solution!(
+    Day07,
+    day: 7,
+    title: "Some Assembly Required",
+    tags: ["circuit", "memoization"],
+);
+
+#[derive(Clone)]
+enum Gate {
+    Value(u16),
+    Wire(String),
+    And(String, String),
+    Or(String, String),
+    LShift(String, u16),
+    RShift(String, u16),
+    Not(String),
+}
+
+fn parse_shift(amount: &str) -> Result<u16, String> {
+    match amount.parse() {
+        Ok(shift) if shift < 16 => Ok(shift),
+        _ => Err(format!("invalid shift amount '{}'", amount)),
+    }
+}
+
+fn parse_gate(instruction: &str) -> Result<Gate, String> {
+    let tokens: Vec<&str> = instruction.split_whitespace().collect();
+
+    let gate = match tokens[..] {
+        [value] => match value.parse::<u16>() {
+            Ok(value) => Gate::Value(value),
+            Err(_) => Gate::Wire(value.to_string()),
+        },
+        ["NOT", wire] => Gate::Not(wire.to_string()),
+        [a, "AND", b] => Gate::And(a.to_string(), b.to_string()),
+        [a, "OR", b] => Gate::Or(a.to_string(), b.to_string()),
+        [wire, "LSHIFT", shift] => Gate::LShift(wire.to_string(), parse_shift(shift)?),
+        [wire, "RSHIFT", shift] => Gate::RShift(wire.to_string(), parse_shift(shift)?),
+        _ => return Err(format!("unrecognised gate '{}'", instruction)),
+    };
+
+    Ok(gate)
+}
+
+pub struct Circuit {
+    gates: HashMap<String, Gate>,
+    /// Unmodified signal on wire a, filled by whichever part needs it first
+    signal_a: OnceCell<Result<u16, SolveError>>,
+}
+
+fn parse_input(input: &str) -> Result<HashMap<String, Gate>, SolveError> {
+    let mut circuit = HashMap::new();
+
+    for (i, line) in input.lines().enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        let error = |reason: String| SolveError::at_line(i + 1, line, reason);
+
+        let (instruction, target) = line
+            .split_once(" -> ")
+            .ok_or_else(|| error("expected '<gate> -> <wire>'".to_string()))?;
+        let gate = parse_gate(instruction).map_err(error)?;
+
+        circuit.insert(target.trim().to_string(), gate);
+    }
+
+    Ok(circuit)
+}
+
+/// Signal on `wire`, where `visiting` holds the wires whose evaluation is in progress
+/// so that a wire feeding back into itself is reported rather than recursed into forever
+fn evaluate(
+    wire: &str,
+    circuit: &HashMap<String, Gate>,
+    cache: &mut HashMap<String, u16>,
+    visiting: &mut HashSet<String>,
+) -> Result<u16, SolveError> {
+    if let Ok(value) = wire.parse::<u16>() {
+        return Ok(value);
+    }
+
+    if let Some(&value) = cache.get(wire) {
+        return Ok(value);
+    }
+
+    let gate = circuit
+        .get(wire)
+        .ok_or_else(|| SolveError::new(format!("wire '{}' has no signal source", wire)))?
+        .clone();
+    if !visiting.insert(wire.to_string()) {
+        return Err(SolveError::new(format!(
+            "wire '{}' depends on itself",
+            wire
+        )));
+    }
+
+    let mut input = |w: &str| evaluate(w, circuit, cache, visiting);
+    let result = match gate {
+        Gate::Value(v) => v,
+        Gate::Wire(w) => input(&w)?,
+        Gate::And(a, b) => input(&a)? & input(&b)?,
+        Gate::Or(a, b) => input(&a)? | input(&b)?,
+        Gate::LShift(w, shift) => input(&w)? << shift,
+        Gate::RShift(w, shift) => input(&w)? >> shift,
+        Gate::Not(w) => !input(&w)?,
+    };
+
+    visiting.remove(wire);
+    cache.insert(wire.to_string(), result);
+    Ok(result)
+}
+
+impl Circuit {
+    /// Signal on wire a, with the wires in `overrides` fixed to the given signals
+    fn evaluate_a(&self, mut overrides: HashMap<String, u16>) -> Result<u16, SolveError> {
+        evaluate("a", &self.gates, &mut overrides, &mut HashSet::new())
+    }
+
+    /// Signal on wire a as the circuit is wired, evaluated once and shared by both parts
+    fn signal_a(&self) -> Result<u16, SolveError> {
+        self.signal_a
+            .get_or_init(|| self.evaluate_a(HashMap::new()))
+            .clone()
+    }
+}
+
+impl Solution for Day07 {
+    type Parsed = Circuit;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        let gates = parse_input(input)?;
+        Ok(Circuit {
+            gates,
+            signal_a: OnceCell::new(),
+        })
+    }
+
+    fn part1(&self, circuit: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        Ok(circuit.signal_a()?.into())
+    }
+
+    fn part2(&self, circuit: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        // Overriding wire b through the cache leaves the parsed gates untouched
+        let signal_a = circuit.signal_a()?;
+        let result = circuit.evaluate_a(HashMap::from([("b".to_string(), signal_a)]))?;
+        Ok(result.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part1() {
+        let input = "123 -> x
+456 -> y
+x AND y -> d
+x OR y -> e
+x LSHIFT 2 -> f
+y RSHIFT 2 -> g
+NOT x -> h
+NOT y -> i
+d -> a";
+        let solution = Day07;
+        assert_eq!(solution.solve_part1(input).unwrap(), 72.into());
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = "3 -> b
+b LSHIFT 1 -> a";
+        let solution = Day07;
+        assert_eq!(solution.solve_part2(input).unwrap(), 12.into());
+    }
+
+    #[test]
+    fn test_part2_reuses_signal_a() {
+        let solution = Day07;
+        let circuit = solution.parse("3 -> b\nb LSHIFT 1 -> a").unwrap();
+        let ctx = Context::default();
+        assert_eq!(solution.part1(&circuit, &ctx).unwrap(), 6.into());
+
+        // Part 2 takes wire a from the cache instead of evaluating the circuit again
+        let circuit = solution.parse("3 -> b\nb LSHIFT 1 -> a").unwrap();
+        circuit.signal_a.set(Ok(5)).unwrap();
+        assert_eq!(solution.part2(&circuit, &ctx).unwrap(), 10.into());
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let solution = Day07;
+        let error = solution.solve_part1("123 -> x\nx XOR y -> a").unwrap_err();
+        assert_eq!(error.line, Some(2));
+        assert_eq!(error.text.as_deref(), Some("x XOR y -> a"));
+
+        assert!(solution.solve_part1("x LSHIFT 16 -> a").is_err());
+        assert!(solution.solve_part1("123 x").is_err());
+
+        // A circuit without wire a still parses; only solving needs it
+        assert!(solution.parse("123 -> x\nNOT x -> h").is_ok());
+        let error = solution.solve_part1("123 -> x").unwrap_err();
+        assert_eq!(error.reason, "wire 'a' has no signal source");
+
+        let error = solution.solve_part1("a -> b\nb -> a").unwrap_err();
+        assert_eq!(error.reason, "wire 'a' depends on itself");
+    }
+}
@@ -0,0 +1,11 @@
+pub const YEAR: u16 = 2015;
+
+days! {
+    day01,
+    day02,
+    day03,
+    day04,
+    day05,
+    day06,
+    day07,
+}
@@ -0,0 +1,18 @@
+//! Advent of Code solutions and the tooling around them.
+//!
+//! - [`days`]: the [`Solution`](days::Solution) trait, the `solution!` and `days!` macros
+//!   that register a day, and the registry of every solved day by year
+//! - [`utils`]: input parsing, grids, navigation and math helpers shared by the solutions
+//! - [`runner`]: running, timing, benchmarking and checking days, and reporting results
+//! - [`client`]: fetching inputs from and submitting answers to the Advent of Code website
+//!
+//! The `aoc2015` binary is a command line interface over this crate.
+
+pub mod client;
+pub mod config;
+pub mod days;
+pub mod migrate;
+pub mod runner;
+pub mod scaffold;
+pub mod utils;
+pub mod watch;
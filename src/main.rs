@@ -1,86 +1,714 @@
-mod days;
-mod utils;
+use aoc2015::client::ledger;
+use aoc2015::client::{self, Client, Fetched, Outcome, SubmitError, Submitter};
+use aoc2015::config::{self, Config};
+use aoc2015::days::{self, Answer, Output};
+use aoc2015::runner::answers;
+use aoc2015::runner::baseline::{self, Verdict};
+use aoc2015::runner::bench::{self, BenchConfig};
+use aoc2015::runner::examples;
+use aoc2015::runner::report::{self, Format};
+use aoc2015::runner::selection::DaySelection;
+use aoc2015::runner::{self, RunOptions, Skip, Source};
+use aoc2015::utils::input::Dirs;
+use aoc2015::{migrate, scaffold, watch};
+use clap::{ArgGroup, Args, Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
 
-use clap::Parser;
-use days::Solution;
-use std::time::Instant;
+#[global_allocator]
+static ALLOCATOR: runner::alloc::CountingAllocator = runner::alloc::CountingAllocator;
 
 #[derive(Parser)]
-#[command(author, version, about = "Advent of Code 2015 Solutions", long_about = None)]
-struct Args {
+#[command(author, version, about = "Advent of Code solutions", long_about = None)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+
+    /// Project configuration file with defaults for the options below
+    #[arg(long, global = true, value_name = "PATH", default_value = config::DEFAULT_PATH)]
+    config: PathBuf,
+
+    #[command(flatten)]
+    run: RunArgs,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List the registered days
+    List {
+        /// Only list the days of this year
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Create the source, input and example files for a new day
+    New {
+        /// Day number (1-25)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Puzzle year [default: from the config file, or 2015]
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Move inputs, examples, submission logs and answers from the flat layout
+    /// (inputs/day01.txt) into per-year directories (inputs/2015/day01.txt)
+    Migrate {
+        /// Year the files in the flat layout belong to [default: from the config file,
+        /// or 2015]
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// File that correct answers are recorded in
+        #[arg(long, value_name = "PATH", default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+
+        /// Directory with the per-day logs of submitted answers
+        #[arg(long, value_name = "DIR", default_value = ledger::DEFAULT_DIR)]
+        ledger: PathBuf,
+    },
+    /// Re-run a day's tests and parts whenever its source, input or example changes
+    Watch {
+        /// Day number (1-25)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Puzzle year [default: from the config file, or 2015]
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// Run part 1, part 2, or both (default: both)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+
+        /// Run on an example instead of the real input: a name from examples/YYYY/dayNN/,
+        /// or all of them if no name is given
+        #[arg(
+            short,
+            long,
+            value_name = "NAME",
+            num_args = 0..=1,
+            default_missing_value = examples::ALL
+        )]
+        example: Option<String>,
+
+        /// How often to check for changes, in seconds
+        #[arg(long, default_value = "0.5", value_name = "SECONDS", value_parser = bench::parse_seconds)]
+        interval: Duration,
+    },
+    /// Download the puzzle input for a day into inputs/YYYY/
+    Fetch {
+        /// Day number (1-25)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Puzzle year [default: from the config file, or 2015]
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+    /// Inspect the project configuration
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommand,
+    },
+    /// Submit an answer, solving the part first if no answer is given
+    Submit {
+        /// Day number (1-25)
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+
+        /// Part to submit
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+
+        /// Answer to submit instead of the computed one
+        #[arg(value_parser = |s: &str| s.parse::<Answer>())]
+        answer: Option<Answer>,
+
+        /// Puzzle year [default: from the config file, or 2015]
+        #[arg(short, long)]
+        year: Option<u16>,
+
+        /// File that correct answers are recorded in
+        #[arg(long, value_name = "PATH", default_value = answers::DEFAULT_PATH)]
+        answers: PathBuf,
+
+        /// Directory with the per-day logs of submitted answers
+        #[arg(long, value_name = "DIR", default_value = ledger::DEFAULT_DIR)]
+        ledger: PathBuf,
+
+        #[command(flatten)]
+        server: ServerArgs,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommand {
+    /// Print the effective configuration: the config file merged with the defaults
+    Show,
+}
+
+#[derive(Args)]
+struct ServerArgs {
+    /// Base URL of the Advent of Code website [default: from the config file, or
+    /// https://adventofcode.com]
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+#[command(group(ArgGroup::new("selection").required(true).args(["day", "days", "all"])))]
+struct RunArgs {
     /// Day number (1-25)
     #[arg(short, long)]
-    day: u8,
+    day: Option<u8>,
+
+    /// Days to run, as a comma-separated list of days and ranges (e.g. 1-7,9)
+    #[arg(long)]
+    days: Option<DaySelection>,
+
+    /// Run every implemented day of the year
+    #[arg(long)]
+    all: bool,
+
+    /// Puzzle year [default: from the config file, or 2015]
+    #[arg(short, long)]
+    year: Option<u16>,
 
     /// Run part 1, part 2, or both (default: both)
     #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
     part: Option<u8>,
 
-    /// Use example input instead of real input
-    #[arg(short, long)]
-    example: bool,
+    /// Run on an example instead of the real input and check its expected answers:
+    /// a name from examples/YYYY/dayNN/, or all of them if no name is given
+    #[arg(
+        short,
+        long,
+        value_name = "NAME",
+        num_args = 0..=1,
+        default_missing_value = examples::ALL
+    )]
+    example: Option<String>,
+
+    /// Read the input from a file instead, or from stdin if PATH is -
+    #[arg(
+        short,
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["example", "days", "all"]
+    )]
+    input: Option<PathBuf>,
+
+    /// Number of days to run at the same time [default: from the config file, or 1]
+    #[arg(
+        short,
+        long,
+        value_name = "N",
+        value_parser = clap::value_parser!(u16).range(1..),
+        conflicts_with = "bench"
+    )]
+    jobs: Option<u16>,
+
+    /// Report the memory allocated by each part: peak bytes in use, number of
+    /// allocations and total bytes allocated
+    #[arg(long, conflicts_with = "jobs")]
+    mem: bool,
+
+    /// Pass the input on exactly as read, without stripping a byte order mark,
+    /// converting CRLF line endings or trimming trailing newlines
+    #[arg(long)]
+    raw: bool,
+
+    /// Stop any part that runs longer than this, in seconds
+    #[arg(long, value_name = "SECONDS", value_parser = bench::parse_seconds)]
+    timeout: Option<Duration>,
+
+    /// Show what parts log on stderr, and the debug values they record;
+    /// repeat (-vv) to include debug logs
+    #[arg(short, long, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Show progress counters of long-running parts on stderr, e.g. hashes per second
+    #[arg(long)]
+    progress: bool,
 
     /// Run benchmarks
     #[arg(short, long)]
     bench: bool,
+
+    /// Time budget per part when benchmarking, in seconds [default: from the config file,
+    /// or 1]
+    #[arg(long, value_name = "SECONDS", value_parser = bench::parse_seconds)]
+    bench_time: Option<Duration>,
+
+    /// Baseline file that benchmark results are compared against
+    #[arg(long, value_name = "PATH", default_value = baseline::DEFAULT_PATH)]
+    baseline: PathBuf,
+
+    /// Save benchmark results to the baseline file
+    #[arg(long, requires = "bench")]
+    save_baseline: bool,
+
+    /// Minimum change in percent before a significant difference counts as a regression
+    #[arg(long, value_name = "PERCENT", default_value_t = baseline::DEFAULT_THRESHOLD)]
+    threshold: f64,
+
+    /// Verify answers against the answers file and fail on any mismatch
+    #[arg(long, conflicts_with_all = ["example", "input"])]
+    check: bool,
+
+    /// Record answers for parts that have no known answer yet
+    #[arg(long, conflicts_with_all = ["example", "input"])]
+    record: bool,
+
+    /// File with known-correct answers
+    #[arg(long, value_name = "PATH", default_value = answers::DEFAULT_PATH)]
+    answers: PathBuf,
+
+    /// Submit answers that aren't in the answers file yet
+    #[arg(long, conflicts_with_all = ["example", "input"])]
+    submit: bool,
+
+    /// Directory with the per-day logs of submitted answers
+    #[arg(long, value_name = "DIR", default_value = ledger::DEFAULT_DIR)]
+    ledger: PathBuf,
+
+    #[command(flatten)]
+    server: ServerArgs,
+
+    /// Output format [default: from the config file, or text]
+    #[arg(short, long, value_enum)]
+    format: Option<Format>,
 }
 
-fn get_solution(day: u8) -> Option<Box<dyn Solution>> {
-    match day {
-        1 => Some(Box::new(days::day01::Day01)),
-        2 => Some(Box::new(days::day02::Day02)),
-        3 => Some(Box::new(days::day03::Day03)),
-        4 => Some(Box::new(days::day04::Day04)),
-        5 => Some(Box::new(days::day05::Day05)),
-        6 => Some(Box::new(days::day06::Day06)),
-        7 => Some(Box::new(days::day07::Day07)),
-        _ => None,
+/// Print a report line, on stderr for machine-readable formats to keep stdout parseable
+fn print_note(format: Format, line: &str) {
+    match format {
+        Format::Text => println!("{}", line),
+        _ => eprintln!("{}", line),
     }
 }
 
-fn main() {
-    let args = Args::parse();
+/// Print the checks of results on examples. Returns whether any answer was wrong.
+fn check_examples(format: Format, jobs: &[runner::Job], results: &[runner::DayResult]) -> bool {
+    let checks = examples::check_results(jobs, results);
+    if !checks.is_empty() {
+        print_note(format, "Checked against expected example answers:");
+        for (name, check) in &checks {
+            print_note(format, &format!("  {}: {}", name, check));
+        }
+    }
+    checks.iter().any(|(_, check)| check.is_wrong())
+}
 
-    let solution = get_solution(args.day).unwrap_or_else(|| {
-        eprintln!("Day {} not implemented yet", args.day);
+/// Verify results against the answers file, recording unknown answers if requested.
+/// Returns whether any answer was wrong.
+fn check_answers(args: &RunArgs, format: Format, results: &[&runner::PartResult]) -> bool {
+    let verification = answers::verify(&args.answers, results, args.record).unwrap_or_else(|e| {
+        eprintln!("Failed to check answers {}: {}", args.answers.display(), e);
         std::process::exit(1);
     });
 
-    let input = if args.example {
-        utils::input::read_example(args.day)
-    } else {
-        utils::input::read_input(args.day)
+    if args.check {
+        print_note(
+            format,
+            &format!("Checked against {}:", args.answers.display()),
+        );
+        for check in &verification.checks {
+            print_note(format, &format!("  {}", check));
+        }
+    }
+    if verification.recorded > 0 {
+        eprintln!(
+            "Recorded {} answer(s) in {}",
+            verification.recorded,
+            args.answers.display()
+        );
+    }
+
+    args.check && verification.any_wrong()
+}
+
+/// Print the comparison of benchmark results with the baseline file, saving them if
+/// requested. Returns whether any stage regressed.
+fn check_baseline(args: &RunArgs, format: Format, results: &[runner::DayResult]) -> bool {
+    let comparisons = baseline::check(&args.baseline, results, args.threshold, args.save_baseline)
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to use baseline {}: {}", args.baseline.display(), e);
+            std::process::exit(1);
+        });
+
+    if !comparisons.is_empty() {
+        print_note(format, &format!("Compared to {}:", args.baseline.display()));
+        for comparison in &comparisons {
+            print_note(format, &format!("  {}", comparison));
+        }
+    }
+    if args.save_baseline {
+        eprintln!("Saved baseline to {}", args.baseline.display());
+    }
+
+    comparisons
+        .iter()
+        .any(|comparison| comparison.verdict == Verdict::Regressed)
+}
+
+fn list_days(year: Option<u16>) {
+    println!("{:<5} {:<4} {:<40} Tags", "Year", "Day", "Title");
+    for registration in days::registrations() {
+        if year.is_some_and(|year| year != registration.year) {
+            continue;
+        }
+        println!(
+            "{:<5} {:<4} {:<40} {}",
+            registration.year,
+            registration.day,
+            registration.title,
+            registration.tags.join(", ")
+        );
+    }
+}
+
+fn new_day(dirs: &Dirs, year: u16, day: u8) {
+    match scaffold::new_day(Path::new("."), dirs, year, day) {
+        Ok(steps) => {
+            for step in steps {
+                println!("{}", step);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to set up day {}: {}", day, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Set up a client for the website, exiting if there is no usable session
+fn connect(server: &ServerArgs, config: &Config, config_path: &Path) -> Client {
+    if config.contact.trim().is_empty() {
+        eprintln!(
+            "Note: set `contact` in {} to a repository URL or email address so the site can reach you",
+            config_path.display()
+        );
+    }
+    config
+        .connect(server.base_url.as_deref())
+        .unwrap_or_else(|e| {
+            eprintln!("Failed to set up client: {}", e);
+            std::process::exit(1);
+        })
+}
+
+fn migrate(year: u16, dirs: &Dirs, answers: &Path, ledger: &Path) {
+    let dirs = [dirs.inputs.as_path(), dirs.examples.as_path(), ledger];
+    match migrate::migrate(&dirs, answers, year) {
+        Ok(steps) if steps.is_empty() => println!("Nothing to migrate"),
+        Ok(steps) => {
+            for step in steps {
+                println!("{}", step);
+            }
+        }
+        Err(e) => {
+            eprintln!("Failed to migrate: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+fn fetch(day: u8, year: u16, server: &ServerArgs, config: &Config, config_path: &Path) {
+    let path = config.dirs().input_path(year, day);
+    match connect(server, config, config_path).fetch_input(year, day, &path) {
+        Ok(Fetched::Downloaded) => println!("Downloaded {}", path.display()),
+        Ok(Fetched::Cached) => println!("Skipped {} (already downloaded)", path.display()),
+        Err(e) => {
+            eprintln!("Failed to fetch input for day {}: {}", day, e);
+            std::process::exit(1);
+        }
+    }
+}
+
+/// Print a submission attempt and whatever could not be saved afterwards
+fn print_outcome(outcome: &Outcome, answers: &Path) {
+    let Outcome {
+        day, part, answer, ..
+    } = outcome;
+    match &outcome.result {
+        Ok(submitted) => {
+            eprintln!(
+                "Day {} part {}: submitted {}: {}",
+                day, part, answer, submitted.verdict
+            );
+            for (path, e) in &submitted.save_errors {
+                eprintln!("Failed to save {}: {}", path.display(), e);
+            }
+            if submitted.verdict == client::Verdict::Correct && submitted.save_errors.is_empty() {
+                eprintln!("Recorded answer in {}", answers.display());
+            }
+            if submitted.verdict == client::Verdict::AlreadySolved && !outcome.is_accepted() {
+                match &submitted.recorded {
+                    Some(recorded) => eprintln!("The recorded answer is {}", recorded),
+                    None => eprintln!(
+                        "No answer recorded in {}, so whether {} is right is unknown",
+                        answers.display(),
+                        answer
+                    ),
+                }
+            }
+        }
+        Err(e @ SubmitError::Unsubmittable) => {
+            eprintln!("Day {} part {}: skipped: {}", day, part, e)
+        }
+        Err(SubmitError::Client(e)) => eprintln!(
+            "Day {} part {}: failed to submit {}: {}",
+            day, part, answer, e
+        ),
+        Err(e) => eprintln!(
+            "Day {} part {}: not submitting {}: {}",
+            day, part, answer, e
+        ),
+    }
+}
+
+fn submit(
+    day: u8,
+    part: u8,
+    answer: Option<Answer>,
+    year: u16,
+    dirs: &Dirs,
+    submitter: &Submitter,
+) {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => {
+            let (jobs, skipped) = runner::jobs(year, &[day], Source::Input(dirs), false);
+            if let Some((day, skip)) = skipped.first() {
+                eprintln!("Can't solve day {} of {}: {}", day, year, skip);
+                std::process::exit(1);
+            }
+            let mut result = jobs[0].run(&[part], &RunOptions::default());
+            match result.parts.remove(0).answer {
+                Ok(answer) => answer,
+                Err(e) => {
+                    eprintln!("Part {} failed: {}", part, e);
+                    std::process::exit(1);
+                }
+            }
+        }
+    };
+
+    let outcome = Outcome {
+        year,
+        day,
+        part,
+        result: submitter.submit(year, day, part, &answer),
+        answer,
     };
+    print_outcome(&outcome, submitter.answers);
+    if !outcome.is_accepted() {
+        std::process::exit(1);
+    }
+}
+
+/// Submit every answer not yet in the answers file.
+/// Returns whether any submission was rejected; skipped answers don't count.
+fn submit_results(
+    args: &RunArgs,
+    config: &Config,
+    config_path: &Path,
+    results: &[&runner::PartResult],
+) -> bool {
+    let client = connect(&args.server, config, config_path);
+    let submitter = Submitter {
+        client: &client,
+        answers: &args.answers,
+        ledger_dir: &args.ledger,
+    };
+    let outcomes = submitter.submit_new(results).unwrap_or_else(|e| {
+        eprintln!("Failed to read answers {}: {}", args.answers.display(), e);
+        std::process::exit(1);
+    });
+    for outcome in &outcomes {
+        print_outcome(outcome, &args.answers);
+    }
+    outcomes
+        .iter()
+        .any(|outcome| !outcome.is_accepted() && !outcome.is_skipped())
+}
 
-    let run_part = |part_num: u8, part_fn: fn(&dyn Solution, &str) -> String| {
-        if args.bench {
-            let iterations = 100;
-            let start = Instant::now();
-            for _ in 0..iterations {
-                let _ = part_fn(solution.as_ref(), &input);
+/// Print the configuration that applies, with the input directory from `AOC_INPUT_DIR`
+/// if set
+fn show_config(path: &Path, config: &Config) {
+    if path.is_file() {
+        println!("# {}", path.display());
+    } else {
+        println!("# {} not found, using the defaults", path.display());
+    }
+    print!("{}", config.effective().to_toml());
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let config = Config::load(&cli.config).unwrap_or_else(|e| {
+        eprintln!("Failed to read config: {}", e);
+        std::process::exit(1);
+    });
+    let dirs = config.dirs();
+    let year = |year: Option<u16>| year.unwrap_or(config.year);
+
+    match cli.command {
+        Some(Command::List { year }) => list_days(year),
+        Some(Command::New { day, year: y }) => new_day(&dirs, year(y), day),
+        Some(Command::Migrate {
+            year: y,
+            answers,
+            ledger,
+        }) => migrate(year(y), &dirs, &answers, &ledger),
+        Some(Command::Config {
+            command: ConfigCommand::Show,
+        }) => show_config(&cli.config, &config),
+        Some(Command::Watch {
+            day,
+            year: y,
+            part,
+            example,
+            interval,
+        }) => {
+            let options = watch::Options {
+                config: cli.config.clone(),
+                dirs,
+                year: year(y),
+                day,
+                part,
+                example,
+                interval,
+            };
+            if let Err(e) = watch::watch(&options) {
+                eprintln!("Failed to watch day {}: {}", day, e);
+                std::process::exit(1);
             }
-            let elapsed = start.elapsed();
-            let avg = elapsed / iterations;
-
-            let result = part_fn(solution.as_ref(), &input);
-            println!("Part {}: {}", part_num, result);
-            println!("Average time ({} runs): {:?}\n", iterations, avg);
-        } else {
-            let start = Instant::now();
-            let result = part_fn(solution.as_ref(), &input);
-            let elapsed = start.elapsed();
-
-            println!("Part {}: {}", part_num, result);
-            println!("Time: {:?}\n", elapsed);
         }
+        Some(Command::Fetch {
+            day,
+            year: y,
+            server,
+        }) => fetch(day, year(y), &server, &config, &cli.config),
+        Some(Command::Submit {
+            day,
+            part,
+            answer,
+            year: y,
+            answers,
+            ledger,
+            server,
+        }) => {
+            let client = connect(&server, &config, &cli.config);
+            let submitter = Submitter {
+                client: &client,
+                answers: &answers,
+                ledger_dir: &ledger,
+            };
+            submit(day, part, answer, year(y), &dirs, &submitter)
+        }
+        None => run(cli.run, &config, &cli.config),
+    }
+}
+
+fn run(args: RunArgs, config: &Config, config_path: &Path) {
+    let year = args.year.unwrap_or(config.year);
+    let dirs = config.dirs();
+    let format = args.format.unwrap_or(config.format);
+    // Benchmarks and allocation counts need days to run alone, whatever the config says
+    let threads = match args.jobs {
+        Some(jobs) => jobs,
+        None if args.bench || args.mem => 1,
+        None => config.jobs,
     };
 
-    match args.part {
-        Some(1) => run_part(1, |s, i| s.part1(i)),
-        Some(2) => run_part(2, |s, i| s.part2(i)),
-        _ => {
-            run_part(1, |s, i| s.part1(i));
-            run_part(2, |s, i| s.part2(i));
+    let parts: &[u8] = match args.part {
+        Some(1) => &[1],
+        Some(2) => &[2],
+        _ => &[1, 2],
+    };
+
+    if days::year(year).is_none() {
+        eprintln!("No days of {} implemented yet", year);
+        std::process::exit(1);
+    }
+    let selection = match args.day {
+        Some(day) => DaySelection::single(day),
+        None => args.days.clone().unwrap_or_else(DaySelection::all),
+    };
+    let source = match (&args.example, &args.input) {
+        (Some(name), _) => Source::Example(&dirs, name),
+        (None, Some(path)) => Source::File(path),
+        (None, None) => Source::Input(&dirs),
+    };
+    let options = RunOptions {
+        bench: args
+            .bench
+            .then(|| BenchConfig::new(args.bench_time.unwrap_or_else(|| config.bench_time()))),
+        timeout: args.timeout,
+        output: Output::verbose(args.verbose, args.progress),
+    };
+    if args.mem {
+        runner::alloc::enable();
+    }
+
+    let (jobs, skipped) = runner::jobs(year, selection.days(), source, args.raw);
+    for (day, skip) in &skipped {
+        match skip {
+            Skip::NotImplemented if args.day.is_some() => {
+                eprintln!("Day {} of {} not implemented yet", day, year);
+                std::process::exit(1);
+            }
+            Skip::NotImplemented if args.all => {}
+            Skip::NotImplemented => eprintln!("Day {} not implemented yet, skipping", day),
+            Skip::Input(e) if args.day.is_some() => {
+                eprintln!("Failed to read input: {}", e);
+                std::process::exit(1);
+            }
+            Skip::Input(e) => eprintln!("Skipping day {}: {}", day, e),
         }
     }
+    if jobs.is_empty() {
+        eprintln!("No implemented days selected");
+        std::process::exit(1);
+    }
+
+    let results = runner::run_jobs(&jobs, parts, &options, threads.into()).unwrap_or_else(|e| {
+        eprintln!("Failed to start {} threads: {}", threads, e);
+        std::process::exit(1);
+    });
+
+    match format {
+        Format::Text if results.len() == 1 => report::print_day(&results[0]),
+        Format::Text => report::print_summary(&results),
+        Format::Json => report::print_json(&results),
+        Format::Csv => report::print_csv(&results),
+    }
+
+    let parts: Vec<_> = results.iter().flat_map(|day| &day.parts).collect();
+    let mut failed = parts.iter().any(|result| result.answer.is_err());
+    if args.example.is_some() && check_examples(format, &jobs, &results) {
+        eprintln!("Some answers do not match the expected example answers");
+        failed = true;
+    }
+    if (args.check || args.record) && check_answers(&args, format, &parts) {
+        eprintln!("Some answers do not match {}", args.answers.display());
+        failed = true;
+    }
+    if args.submit && submit_results(&args, config, config_path, &parts) {
+        eprintln!("Some answers were not accepted");
+        failed = true;
+    }
+    if args.bench && check_baseline(&args, format, &results) {
+        eprintln!("Performance regressions detected");
+        failed = true;
+    }
+    if failed {
+        std::process::exit(1);
+    }
 }
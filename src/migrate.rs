@@ -0,0 +1,149 @@
+use crate::runner::answers::Answers;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// What happened to a single file while migrating
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Moved { from: PathBuf, to: PathBuf },
+    Rewrote(PathBuf),
+    Skipped { from: PathBuf, to: PathBuf },
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Moved { from, to } => write!(f, "Moved {} to {}", from.display(), to.display()),
+            Step::Rewrote(path) => write!(
+                f,
+                "Moved answers in {} into per-year tables",
+                path.display()
+            ),
+            Step::Skipped { from, to } => write!(
+                f,
+                "Skipped {} ({} already exists)",
+                from.display(),
+                to.display()
+            ),
+        }
+    }
+}
+
+/// Whether a file or directory name belongs to a day in the flat layout:
+/// `dayNN`, optionally with an extension
+fn is_day_entry(name: &str) -> bool {
+    let stem = name.split_once('.').map_or(name, |(stem, _)| stem);
+    stem.len() == 5 && stem.starts_with("day") && stem[3..].bytes().all(|b| b.is_ascii_digit())
+}
+
+/// Move every day's files and directories in `dir` into `dir/YYYY/`, never replacing
+/// anything that is already there
+fn migrate_dir(dir: &Path, year: u16) -> io::Result<Vec<Step>> {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(e),
+    };
+    let mut names = Vec::new();
+    for entry in entries {
+        let name = entry?.file_name();
+        if name.to_str().is_some_and(is_day_entry) {
+            names.push(name);
+        }
+    }
+    names.sort();
+
+    let target = dir.join(year.to_string());
+    let mut steps = Vec::new();
+    for name in names {
+        let from = dir.join(&name);
+        let to = target.join(&name);
+        if to.exists() {
+            steps.push(Step::Skipped { from, to });
+            continue;
+        }
+        fs::create_dir_all(&target)?;
+        fs::rename(&from, &to)?;
+        steps.push(Step::Moved { from, to });
+    }
+    Ok(steps)
+}
+
+/// Move inputs, examples and submission logs from the flat layout (`inputs/day01.txt`)
+/// into per-year directories (`inputs/2015/day01.txt`), and the `[dayNN]` tables of the
+/// answers file into `[YYYY.dayNN]` tables. Running it again does nothing.
+pub fn migrate(dirs: &[&Path], answers: &Path, year: u16) -> io::Result<Vec<Step>> {
+    let mut steps = Vec::new();
+    for dir in dirs {
+        steps.extend(migrate_dir(dir, year)?);
+    }
+    if Answers::migrate(answers, year)? {
+        steps.push(Step::Rewrote(answers.to_path_buf()));
+    }
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_day_entry() {
+        assert!(is_day_entry("day01.txt"));
+        assert!(is_day_entry("day25"));
+        assert!(is_day_entry("day07.json"));
+        assert!(!is_day_entry("2015"));
+        assert!(!is_day_entry(".gitkeep"));
+        assert!(!is_day_entry("day1.txt"));
+        assert!(!is_day_entry("dayXX.txt"));
+    }
+
+    #[test]
+    fn test_migrate() {
+        let dir = tempfile::tempdir().unwrap();
+        let inputs = dir.path().join("inputs");
+        let examples = dir.path().join("examples");
+        let answers = dir.path().join("answers.toml");
+        fs::create_dir_all(inputs.join("2015")).unwrap();
+        fs::create_dir_all(examples.join("day01")).unwrap();
+        fs::write(inputs.join("day01.txt"), "(())").unwrap();
+        fs::write(inputs.join("day02.txt"), "old").unwrap();
+        fs::write(inputs.join("2015/day02.txt"), "new").unwrap();
+        fs::write(inputs.join(".gitkeep"), "").unwrap();
+        fs::write(examples.join("day01/a.txt"), "()").unwrap();
+        fs::write(&answers, "[day01]\npart1 = 0\n").unwrap();
+
+        let steps = migrate(&[&inputs, &examples], &answers, 2015).unwrap();
+        assert_eq!(
+            steps,
+            vec![
+                Step::Moved {
+                    from: inputs.join("day01.txt"),
+                    to: inputs.join("2015/day01.txt"),
+                },
+                Step::Skipped {
+                    from: inputs.join("day02.txt"),
+                    to: inputs.join("2015/day02.txt"),
+                },
+                Step::Moved {
+                    from: examples.join("day01"),
+                    to: examples.join("2015/day01"),
+                },
+                Step::Rewrote(answers.clone()),
+            ]
+        );
+        assert_eq!(
+            fs::read_to_string(inputs.join("2015/day02.txt")).unwrap(),
+            "new"
+        );
+        assert!(examples.join("2015/day01/a.txt").is_file());
+        assert!(inputs.join(".gitkeep").is_file());
+
+        let steps = migrate(&[&inputs, &examples], &answers, 2015).unwrap();
+        assert!(steps
+            .iter()
+            .all(|step| matches!(step, Step::Skipped { .. })));
+    }
+}
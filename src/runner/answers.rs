@@ -0,0 +1,283 @@
+use super::PartResult;
+use crate::days::Answer;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_PATH: &str = "answers.toml";
+
+/// Known answers for both parts of a day
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+struct DayAnswers {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part1: Option<Answer>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    part2: Option<Answer>,
+}
+
+/// Known-correct answers, stored as `[YYYY.dayNN]` tables with `part1` and `part2` keys
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Answers {
+    years: BTreeMap<String, BTreeMap<String, DayAnswers>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Correct,
+    Wrong { expected: Answer },
+    Unknown,
+}
+
+/// The verification status of a single result
+#[derive(Debug, Clone)]
+pub struct Check {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub status: Status,
+}
+
+/// The outcome of checking results against an answers file
+#[derive(Debug, Default)]
+pub struct Verification {
+    pub checks: Vec<Check>,
+    /// How many answers that weren't known yet were recorded
+    pub recorded: usize,
+}
+
+impl Verification {
+    pub fn any_wrong(&self) -> bool {
+        self.checks.iter().any(Check::is_wrong)
+    }
+}
+
+/// Check results against the answers file at `path`. With `record`, answers that aren't
+/// known yet are added to the file; answers of puzzles without one are never recorded.
+pub fn verify(path: &Path, results: &[&PartResult], record: bool) -> io::Result<Verification> {
+    let mut answers = Answers::load(path)?;
+    let checks: Vec<_> = results
+        .iter()
+        .filter_map(|result| answers.check(result))
+        .collect();
+
+    let mut recorded = 0;
+    if record {
+        for check in &checks {
+            if check.status == Status::Unknown && check.answer != Answer::None {
+                answers.set(check.year, check.day, check.part, &check.answer);
+                recorded += 1;
+            }
+        }
+        if recorded > 0 {
+            answers.save(path)?;
+        }
+    }
+    Ok(Verification { checks, recorded })
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{:02}", day)
+}
+
+impl Answers {
+    /// Load the answers file, or no answers if it doesn't exist yet
+    pub fn load(path: &Path) -> io::Result<Answers> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let contents = toml::to_string(self).map_err(io::Error::other)?;
+        fs::write(path, contents)
+    }
+
+    /// The known answer for a part, if any
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<&Answer> {
+        let answers = self.years.get(&year.to_string())?.get(&day_key(day))?;
+        match part {
+            1 => answers.part1.as_ref(),
+            2 => answers.part2.as_ref(),
+            _ => None,
+        }
+    }
+
+    /// Record the answer for a part, replacing any previous one; days only have parts 1 and 2
+    pub fn set(&mut self, year: u16, day: u8, part: u8, answer: &Answer) {
+        let answers = self
+            .years
+            .entry(year.to_string())
+            .or_default()
+            .entry(day_key(day))
+            .or_default();
+        let slot = match part {
+            1 => &mut answers.part1,
+            2 => &mut answers.part2,
+            _ => return,
+        };
+        *slot = Some(answer.clone());
+    }
+
+    /// Check a result against its known answer; failed parts have nothing to check
+    pub fn check(&self, result: &PartResult) -> Option<Check> {
+        Check::new(result, self.get(result.year, result.day, result.part))
+    }
+
+    /// Move the `[dayNN]` tables of an answers file from before answers were kept per year
+    /// into `[YYYY.dayNN]` tables. Returns whether anything was moved.
+    pub fn migrate(path: &Path, year: u16) -> io::Result<bool> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(false),
+            Err(e) => return Err(e),
+        };
+        match migrate_table(&contents, year)? {
+            Some(migrated) => fs::write(path, migrated).map(|()| true),
+            None => Ok(false),
+        }
+    }
+}
+
+fn migrate_table(contents: &str, year: u16) -> io::Result<Option<String>> {
+    let invalid = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+    let mut table: toml::Table = toml::from_str(contents).map_err(|e| invalid(e.to_string()))?;
+    let legacy: Vec<String> = table
+        .keys()
+        .filter(|key| key.starts_with("day"))
+        .cloned()
+        .collect();
+    if legacy.is_empty() {
+        return Ok(None);
+    }
+
+    let days: Vec<_> = legacy
+        .into_iter()
+        .filter_map(|key| table.remove(&key).map(|value| (key, value)))
+        .collect();
+    let toml::Value::Table(year) = table
+        .entry(year.to_string())
+        .or_insert_with(|| toml::Value::Table(toml::Table::new()))
+    else {
+        return Err(invalid(format!("[{}] is not a table", year)));
+    };
+    for (key, value) in days {
+        // Answers already kept for the year win over the legacy ones
+        year.entry(key).or_insert(value);
+    }
+    toml::to_string(&table).map(Some).map_err(io::Error::other)
+}
+
+impl Check {
+    /// Compare a result with the expected answer, if there is one
+    pub fn new(result: &PartResult, expected: Option<&Answer>) -> Option<Check> {
+        let answer = result.answer.as_ref().ok()?;
+        let status = match expected {
+            Some(expected) if answer.matches(expected) => Status::Correct,
+            Some(expected) => Status::Wrong {
+                expected: expected.clone(),
+            },
+            None => Status::Unknown,
+        };
+
+        Some(Check {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            answer: answer.clone(),
+            status,
+        })
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(self.status, Status::Wrong { .. })
+    }
+}
+
+impl fmt::Display for Check {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {} part {}: {} ", self.day, self.part, self.answer)?;
+        match &self.status {
+            Status::Correct => write!(f, "✓ correct"),
+            Status::Wrong { expected } => write!(f, "✗ wrong (expected {})", expected),
+            Status::Unknown => write!(f, "? unknown"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip() {
+        let mut answers = Answers::default();
+        answers.set(2015, 1, 1, &Answer::Int(-232));
+        answers.set(2015, 1, 2, &Answer::Unsigned(1783));
+        answers.set(2015, 7, 1, &Answer::from("abc"));
+        answers.set(2016, 1, 1, &Answer::Int(12));
+        answers.set(2016, 1, 3, &Answer::Int(13));
+
+        let contents = toml::to_string(&answers).unwrap();
+        assert!(contents.contains("[2015.day01]"));
+        assert!(contents.contains("part1 = -232"));
+
+        let answers: Answers = toml::from_str(&contents).unwrap();
+        assert_eq!(answers.get(2015, 1, 1), Some(&Answer::Int(-232)));
+        assert_eq!(answers.get(2015, 1, 2), Some(&Answer::Int(1783)));
+        assert_eq!(answers.get(2015, 7, 1), Some(&Answer::from("abc")));
+        assert_eq!(answers.get(2015, 7, 2), None);
+        assert_eq!(answers.get(2015, 2, 1), None);
+        assert_eq!(answers.get(2016, 1, 1), Some(&Answer::Int(12)));
+        assert_eq!(answers.get(2016, 1, 2), None);
+        assert_eq!(answers.get(2015, 1, 3), None);
+    }
+
+    #[test]
+    fn test_verify() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join(DEFAULT_PATH);
+        fs::write(&path, "[2015.day01]\npart1 = 3\n").unwrap();
+
+        let results = [
+            PartResult::test(1, Answer::Int(3)),
+            PartResult::test(2, Answer::Int(5)),
+        ];
+        let results: Vec<_> = results.iter().collect();
+        let verification = verify(&path, &results, false).unwrap();
+        let statuses: Vec<_> = verification.checks.iter().map(|c| &c.status).collect();
+        assert_eq!(statuses, [&Status::Correct, &Status::Unknown]);
+        assert_eq!(verification.recorded, 0);
+
+        let verification = verify(&path, &results, true).unwrap();
+        assert_eq!(verification.recorded, 1);
+        assert_eq!(
+            Answers::load(&path).unwrap().get(2015, 1, 2),
+            Some(&Answer::Int(5))
+        );
+
+        let wrong = [PartResult::test(2, Answer::Int(6))];
+        let verification = verify(&path, &wrong.iter().collect::<Vec<_>>(), true).unwrap();
+        assert!(verification.any_wrong());
+        assert_eq!(verification.recorded, 0);
+    }
+
+    #[test]
+    fn test_migrate() {
+        let legacy = "[day01]\npart1 = 1\n\n[day02]\npart1 = 2\n\n[2015.day02]\npart1 = 3\n";
+        let migrated = migrate_table(legacy, 2015).unwrap().unwrap();
+        let answers: Answers = toml::from_str(&migrated).unwrap();
+        assert_eq!(answers.get(2015, 1, 1), Some(&Answer::Int(1)));
+        assert_eq!(answers.get(2015, 2, 1), Some(&Answer::Int(3)));
+
+        assert_eq!(migrate_table(&migrated, 2015).unwrap(), None);
+    }
+}
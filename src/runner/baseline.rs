@@ -0,0 +1,313 @@
+use super::bench::Stats;
+use super::DayResult;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const DEFAULT_PATH: &str = "bench/baseline.json";
+
+/// Changes smaller than this (in percent) are treated as noise, even when significant
+pub const DEFAULT_THRESHOLD: f64 = 5.0;
+
+/// Part number the parse stage of a day is stored under
+pub const PARSE: u8 = 0;
+
+/// Two-sided 95% critical values of Student's t distribution for 1 to 30 degrees of freedom
+const T_CRITICAL: [f64; 30] = [
+    12.706, 4.303, 3.182, 2.776, 2.571, 2.447, 2.365, 2.306, 2.262, 2.228, 2.201, 2.179, 2.160,
+    2.145, 2.131, 2.120, 2.110, 2.101, 2.093, 2.086, 2.080, 2.074, 2.069, 2.064, 2.060, 2.056,
+    2.052, 2.048, 2.045, 2.042,
+];
+
+/// Stored benchmark statistics for one stage of a day on one input
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    /// The part, or [`PARSE`] for the parse stage
+    pub part: u8,
+    pub input_hash: String,
+    pub samples: usize,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    pub stddev_ns: f64,
+}
+
+impl Entry {
+    fn new(result: &DayResult, part: u8, stats: &Stats) -> Self {
+        Entry {
+            year: result.year,
+            day: result.day,
+            part,
+            input_hash: result.input_hash.clone(),
+            samples: stats.samples,
+            mean_ns: stats.mean.as_nanos() as f64,
+            median_ns: stats.median.as_nanos() as f64,
+            stddev_ns: stats.stddev.as_nanos() as f64,
+        }
+    }
+
+    fn matches(&self, result: &DayResult, part: u8) -> bool {
+        self.year == result.year
+            && self.day == result.day
+            && self.part == part
+            && self.input_hash == result.input_hash
+    }
+}
+
+/// The benchmarked stages of a day: the parse stage first, then each part
+fn stages(result: &DayResult) -> impl Iterator<Item = (u8, &Stats)> {
+    std::iter::once((PARSE, &result.parse))
+        .chain(result.parts.iter().map(|part| (part.part, &part.timing)))
+        .filter_map(|(part, timing)| Some((part, timing.stats.as_ref()?)))
+}
+
+/// A file of benchmark results to compare later runs against
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Regressed,
+    Improved,
+    Unchanged,
+}
+
+/// The difference between a benchmark result and its baseline
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub old_mean_ns: f64,
+    pub new_mean_ns: f64,
+    pub change_pct: f64,
+    pub verdict: Verdict,
+}
+
+impl Baseline {
+    /// Load a baseline file, or an empty baseline if it doesn't exist yet
+    pub fn load(path: &Path) -> io::Result<Baseline> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Store the benchmarked stages of a day, replacing any previous entries for the same
+    /// stages and input
+    pub fn record(&mut self, result: &DayResult) {
+        for (part, stats) in stages(result) {
+            self.entries.retain(|entry| !entry.matches(result, part));
+            self.entries.push(Entry::new(result, part, stats));
+        }
+        self.entries
+            .sort_by_key(|entry| (entry.year, entry.day, entry.part));
+    }
+
+    /// Compare the benchmarked stages of a day against the stored entries for the same input
+    pub fn compare(&self, result: &DayResult, threshold_pct: f64) -> Vec<Comparison> {
+        stages(result)
+            .filter_map(|(part, stats)| {
+                let old = self
+                    .entries
+                    .iter()
+                    .find(|entry| entry.matches(result, part))?;
+                let new = Entry::new(result, part, stats);
+                Some(compare_entries(old, &new, threshold_pct))
+            })
+            .collect()
+    }
+}
+
+fn compare_entries(old: &Entry, new: &Entry, threshold_pct: f64) -> Comparison {
+    let change_pct = (new.mean_ns - old.mean_ns) / old.mean_ns * 100.0;
+    let verdict = if change_pct.abs() < threshold_pct || !is_significant(old, new) {
+        Verdict::Unchanged
+    } else if change_pct > 0.0 {
+        Verdict::Regressed
+    } else {
+        Verdict::Improved
+    };
+
+    Comparison {
+        day: new.day,
+        part: new.part,
+        old_mean_ns: old.mean_ns,
+        new_mean_ns: new.mean_ns,
+        change_pct,
+        verdict,
+    }
+}
+
+/// Compare results against the baseline file at `path`, then store them in it if `save`
+/// is set
+pub fn check(
+    path: &Path,
+    results: &[DayResult],
+    threshold_pct: f64,
+    save: bool,
+) -> io::Result<Vec<Comparison>> {
+    let mut baseline = Baseline::load(path)?;
+    let comparisons = results
+        .iter()
+        .flat_map(|result| baseline.compare(result, threshold_pct))
+        .collect();
+    if save {
+        for result in results {
+            baseline.record(result);
+        }
+        baseline.save(path)?;
+    }
+    Ok(comparisons)
+}
+
+/// Welch's t-test on the difference of the means at the 95% level
+fn is_significant(old: &Entry, new: &Entry) -> bool {
+    let var_old = old.stddev_ns.powi(2) / old.samples as f64;
+    let var_new = new.stddev_ns.powi(2) / new.samples as f64;
+    let standard_error = (var_old + var_new).sqrt();
+    if standard_error == 0.0 {
+        return old.mean_ns != new.mean_ns;
+    }
+
+    let t = (new.mean_ns - old.mean_ns).abs() / standard_error;
+
+    // Welch-Satterthwaite approximation of the degrees of freedom
+    let df_old = (old.samples as f64 - 1.0).max(1.0);
+    let df_new = (new.samples as f64 - 1.0).max(1.0);
+    let df = (var_old + var_new).powi(2) / (var_old.powi(2) / df_old + var_new.powi(2) / df_new);
+    let critical = T_CRITICAL
+        .get((df.floor() as usize).saturating_sub(1))
+        .copied()
+        .unwrap_or(1.96);
+
+    t > critical
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self.verdict {
+            Verdict::Regressed => "REGRESSED",
+            Verdict::Improved => "improved",
+            Verdict::Unchanged => "no significant change",
+        };
+        let stage = match self.part {
+            PARSE => "parse".to_string(),
+            part => format!("part {}", part),
+        };
+        write!(
+            f,
+            "Day {} {}: {:?} -> {:?} ({:+.1}%) {}",
+            self.day,
+            stage,
+            std::time::Duration::from_nanos(self.old_mean_ns as u64),
+            std::time::Duration::from_nanos(self.new_mean_ns as u64),
+            self.change_pct,
+            label
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::{PartResult, Timing};
+    use std::time::Duration;
+
+    fn entry(samples: usize, mean_ns: f64, stddev_ns: f64) -> Entry {
+        Entry {
+            year: 2015,
+            day: 1,
+            part: 1,
+            input_hash: String::new(),
+            samples,
+            mean_ns,
+            median_ns: mean_ns,
+            stddev_ns,
+        }
+    }
+
+    fn timing(mean_us: u64) -> Timing {
+        let mean = Duration::from_micros(mean_us);
+        Timing {
+            elapsed: mean,
+            iterations: 100,
+            stats: Some(Stats {
+                samples: 100,
+                iterations: 100,
+                mean,
+                median: mean,
+                min: mean,
+                max: mean,
+                stddev: Duration::from_nanos(10),
+                p95: mean,
+                p99: mean,
+            }),
+            memory: None,
+        }
+    }
+
+    fn day(parse_us: u64, part1_us: u64) -> DayResult {
+        DayResult {
+            year: 2015,
+            day: 1,
+            parse: timing(parse_us),
+            parse_error: None,
+            parts: vec![PartResult {
+                timing: timing(part1_us),
+                input_hash: "abc".to_string(),
+                ..PartResult::test(1, 0.into())
+            }],
+            input_hash: "abc".to_string(),
+            concurrent: false,
+            example: None,
+        }
+    }
+
+    #[test]
+    fn test_compare_parse() {
+        let mut baseline = Baseline::default();
+        baseline.record(&day(100, 50));
+        assert_eq!(baseline.entries.len(), 2);
+        assert_eq!(baseline.entries[0].part, PARSE);
+
+        let comparisons = baseline.compare(&day(200, 50), DEFAULT_THRESHOLD);
+        let verdicts: Vec<_> = comparisons.iter().map(|c| (c.part, c.verdict)).collect();
+        assert_eq!(
+            verdicts,
+            vec![(PARSE, Verdict::Regressed), (1, Verdict::Unchanged)]
+        );
+        assert!(comparisons[0].to_string().starts_with("Day 1 parse:"));
+    }
+
+    #[test]
+    fn test_is_significant() {
+        assert!(is_significant(
+            &entry(100, 1000.0, 10.0),
+            &entry(100, 1100.0, 10.0)
+        ));
+        assert!(!is_significant(
+            &entry(100, 1000.0, 500.0),
+            &entry(100, 1010.0, 500.0)
+        ));
+        // Few samples need a much larger difference
+        assert!(!is_significant(
+            &entry(3, 1000.0, 100.0),
+            &entry(3, 1200.0, 100.0)
+        ));
+    }
+}
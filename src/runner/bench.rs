@@ -0,0 +1,167 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Fast parts are run in batches so each sample takes at least this long,
+/// which keeps timer resolution from dominating the measurement
+const MIN_SAMPLE_TIME: Duration = Duration::from_micros(500);
+const MIN_SAMPLES: u64 = 3;
+const MAX_SAMPLES: u64 = 500;
+
+/// How long to spend benchmarking a single part
+#[derive(Debug, Clone, Copy)]
+pub struct BenchConfig {
+    pub warmup: Duration,
+    pub budget: Duration,
+}
+
+impl BenchConfig {
+    /// Measure for roughly `budget`, after a warmup of a tenth of that
+    pub fn new(budget: Duration) -> Self {
+        BenchConfig {
+            warmup: budget / 10,
+            budget,
+        }
+    }
+}
+
+/// Parse a positive number of seconds, e.g. `0.5`
+pub fn parse_seconds(s: &str) -> Result<Duration, String> {
+    let seconds: f64 = s.parse().map_err(|_| format!("invalid number '{}'", s))?;
+    if !seconds.is_finite() || seconds <= 0.0 {
+        return Err(format!(
+            "expected a positive number of seconds, got '{}'",
+            s
+        ));
+    }
+    Ok(Duration::from_secs_f64(seconds))
+}
+
+/// Summary statistics of the time per run
+#[derive(Debug, Clone)]
+pub struct Stats {
+    pub samples: usize,
+    pub iterations: u64,
+    pub mean: Duration,
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub stddev: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+}
+
+fn to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.max(0.0).round() as u64)
+}
+
+/// Linear interpolation between the closest ranks of sorted samples
+fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    let rank = pct / 100.0 * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
+impl Stats {
+    /// Compute statistics from per-run times in nanoseconds
+    pub fn from_samples(samples: &[f64], iterations: u64) -> Stats {
+        assert!(
+            !samples.is_empty(),
+            "Cannot compute statistics without samples"
+        );
+
+        let mut sorted = samples.to_vec();
+        sorted.sort_by(f64::total_cmp);
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+
+        Stats {
+            samples: sorted.len(),
+            iterations,
+            mean: to_duration(mean),
+            median: to_duration(percentile(&sorted, 50.0)),
+            min: to_duration(sorted[0]),
+            max: to_duration(sorted[sorted.len() - 1]),
+            stddev: to_duration(variance.sqrt()),
+            p95: to_duration(percentile(&sorted, 95.0)),
+            p99: to_duration(percentile(&sorted, 99.0)),
+        }
+    }
+}
+
+/// Benchmark `f`, returning its last result and the timing statistics.
+///
+/// After a warmup, the time per run is estimated and used to pick a batch size
+/// and sample count that fill the configured budget.
+pub fn run<R>(config: &BenchConfig, mut f: impl FnMut() -> R) -> (R, Stats) {
+    let start = Instant::now();
+    let mut warmup_runs: u32 = 0;
+    let mut result = loop {
+        let result = black_box(f());
+        warmup_runs += 1;
+        if start.elapsed() >= config.warmup {
+            break result;
+        }
+    };
+    let estimate = (start.elapsed() / warmup_runs).max(Duration::from_nanos(1));
+
+    let batch = if estimate >= MIN_SAMPLE_TIME {
+        1
+    } else {
+        MIN_SAMPLE_TIME.as_nanos().div_ceil(estimate.as_nanos()) as u64
+    };
+    let sample_time = estimate.as_nanos() * batch as u128;
+    let sample_count =
+        ((config.budget.as_nanos() / sample_time) as u64).clamp(MIN_SAMPLES, MAX_SAMPLES);
+
+    let mut samples = Vec::with_capacity(sample_count as usize);
+    for _ in 0..sample_count {
+        let start = Instant::now();
+        for _ in 0..batch {
+            result = black_box(f());
+        }
+        samples.push(start.elapsed().as_nanos() as f64 / batch as f64);
+    }
+
+    let stats = Stats::from_samples(&samples, sample_count * batch);
+    (result, stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&[5.0, 1.0, 3.0, 2.0, 4.0], 5);
+        assert_eq!(stats.samples, 5);
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+    }
+
+    #[test]
+    fn test_percentile() {
+        let sorted = [10.0, 20.0, 30.0, 40.0];
+        assert_eq!(percentile(&sorted, 0.0), 10.0);
+        assert_eq!(percentile(&sorted, 50.0), 25.0);
+        assert_eq!(percentile(&sorted, 100.0), 40.0);
+    }
+
+    #[test]
+    fn test_run_respects_minimum_samples() {
+        let config = BenchConfig::new(Duration::ZERO);
+        let (result, stats) = run(&config, || 42);
+        assert_eq!(result, 42);
+        assert_eq!(stats.samples, MIN_SAMPLES as usize);
+    }
+}
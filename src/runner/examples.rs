@@ -0,0 +1,161 @@
+use super::answers::{Check, Status};
+use super::{DayResult, Job, PartResult};
+use crate::days::Answer;
+use crate::utils::input::{self, Dirs, InputError};
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Selects every example of a day
+pub const ALL: &str = "all";
+
+/// Expected answers, read from the `.toml` file next to an example
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct Expected {
+    part1: Option<Answer>,
+    part2: Option<Answer>,
+}
+
+/// An example input with the answers it should produce, if known
+#[derive(Debug, Clone)]
+pub struct Example {
+    pub name: String,
+    pub input: String,
+    expected: Expected,
+}
+
+impl Example {
+    pub fn expected(&self, part: u8) -> Option<&Answer> {
+        match part {
+            1 => self.expected.part1.as_ref(),
+            _ => self.expected.part2.as_ref(),
+        }
+    }
+
+    /// Check a result on this example against its expected answer
+    pub fn check(&self, result: &PartResult) -> Option<Check> {
+        Check::new(result, self.expected(result.part))
+    }
+}
+
+fn read_expected(path: &Path) -> io::Result<Expected> {
+    match fs::read_to_string(path) {
+        Ok(contents) => toml::from_str(&contents).map_err(|e| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{}: {}", path.display(), e),
+            )
+        }),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Expected::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Every example of a day: `examples/YYYY/dayNN.txt` as the default example, followed by
+/// each `examples/YYYY/dayNN/<name>.txt` by name, with expected answers from `<name>.toml`
+pub fn load_all(dirs: &Dirs, year: u16, day: u8) -> Result<Vec<Example>, InputError> {
+    dirs.example_files(year, day)?
+        .into_iter()
+        .map(|path| {
+            let read_error = |source| InputError::Read {
+                path: path.clone(),
+                source,
+            };
+            if path == dirs.example_path(year, day) {
+                return Ok(Example {
+                    name: input::DEFAULT_EXAMPLE.to_string(),
+                    input: dirs.read_example(year, day)?,
+                    expected: Expected::default(),
+                });
+            }
+            Ok(Example {
+                name: path
+                    .file_stem()
+                    .unwrap_or_default()
+                    .to_string_lossy()
+                    .into_owned(),
+                input: fs::read_to_string(&path).map_err(read_error)?,
+                expected: read_expected(&path.with_extension("toml")).map_err(read_error)?,
+            })
+        })
+        .collect()
+}
+
+/// The examples of a day matching `name`, or all of them for [`ALL`]
+pub fn select(dirs: &Dirs, year: u16, day: u8, name: &str) -> Result<Vec<Example>, InputError> {
+    let examples = load_all(dirs, year, day)?;
+    let selected: Vec<_> = examples
+        .into_iter()
+        .filter(|example| name == ALL || example.name == name)
+        .collect();
+    if selected.is_empty() {
+        return Err(InputError::MissingExample {
+            day,
+            name: (name != ALL).then(|| name.to_string()),
+            path: dirs.example_path(year, day),
+            dir: dirs.example_dir(year, day),
+            available: dirs.example_files(year, day)?,
+        });
+    }
+    Ok(selected)
+}
+
+/// Check the results of jobs on examples against the examples' expected answers, skipping
+/// parts without one. `results` are in the same order as `jobs`.
+pub fn check_results<'a>(jobs: &'a [Job], results: &'a [DayResult]) -> Vec<(&'a str, Check)> {
+    jobs.iter()
+        .zip(results)
+        .filter_map(|(job, result)| job.example.as_ref().map(|example| (example, result)))
+        .flat_map(|(example, result)| {
+            result
+                .parts
+                .iter()
+                .filter_map(move |part| example.check(part))
+                .filter(|check| check.status != Status::Unknown)
+                .map(move |check| (example.name.as_str(), check))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+    use crate::runner::{Job, RunOptions};
+
+    /// Every example with expected answers must produce them, with its input cleaned up
+    /// the way a run cleans it up
+    #[test]
+    fn test_examples() {
+        for registration in days::registrations() {
+            let (year, day) = (registration.year, registration.day);
+            for example in load_all(&Dirs::default(), year, day).unwrap() {
+                let input = example.input.clone();
+                let job = Job::new(registration, &input, Some(example), false);
+                let example = job.example.as_ref().unwrap();
+                let result = job.run(&[1, 2], &RunOptions::default());
+                for part in &result.parts {
+                    let Some(expected) = example.expected(part.part) else {
+                        continue;
+                    };
+                    let label = format!(
+                        "{} day {} example {:?} part {}",
+                        part.year, part.day, example.name, part.part
+                    );
+                    match &part.answer {
+                        Ok(answer) => assert!(
+                            answer.matches(expected),
+                            "{}: got {}, expected {}",
+                            label,
+                            answer,
+                            expected
+                        ),
+                        Err(e) => panic!("{}: {}", label, e),
+                    }
+                }
+            }
+        }
+    }
+}
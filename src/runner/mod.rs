@@ -0,0 +1,418 @@
+pub mod alloc;
+pub mod answers;
+pub mod baseline;
+pub mod bench;
+pub mod examples;
+pub mod report;
+pub mod selection;
+
+use crate::days::{self, Answer, Context, Output, Registration, SolveError};
+use crate::utils::input::{self, Dirs, InputError};
+use alloc::MemStats;
+use bench::{BenchConfig, Stats};
+use examples::Example;
+use rayon::prelude::*;
+use rayon::{ThreadPoolBuildError, ThreadPoolBuilder};
+use std::fmt;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// The year run when none is given
+pub const DEFAULT_YEAR: u16 = 2015;
+
+/// Time spent on one stage of a day
+#[derive(Debug, Clone, Default)]
+pub struct Timing {
+    /// Time per run (the median when benchmarking)
+    pub elapsed: Duration,
+    pub iterations: u64,
+    /// Timing statistics, only present when benchmarking
+    pub stats: Option<Stats>,
+    /// Memory allocated by the first run, only present when counting allocations
+    pub memory: Option<MemStats>,
+}
+
+/// The outcome of running a single part of a day
+pub struct PartResult {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Result<Answer, SolveError>,
+    pub timing: Timing,
+    pub input_hash: String,
+    /// Debug values the part recorded on its first run, only kept when verbose
+    pub values: Vec<(String, String)>,
+}
+
+#[cfg(test)]
+impl PartResult {
+    /// A result of day 1 of the default year with the given answer and no timings
+    pub(crate) fn test(part: u8, answer: Answer) -> Self {
+        PartResult {
+            year: DEFAULT_YEAR,
+            day: 1,
+            part,
+            answer: Ok(answer),
+            timing: Timing::default(),
+            input_hash: String::new(),
+            values: Vec::new(),
+        }
+    }
+}
+
+/// The outcome of parsing a day's input and running its parts
+pub struct DayResult {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Timing,
+    /// Why the input could not be parsed; every part then fails with the same error
+    pub parse_error: Option<SolveError>,
+    pub parts: Vec<PartResult>,
+    pub input_hash: String,
+    /// Other days ran at the same time, so the timings are not comparable to isolated runs
+    pub concurrent: bool,
+    /// Name of the example the day ran on, if any
+    pub example: Option<String>,
+}
+
+/// How days are run
+#[derive(Debug, Clone, Copy, Default)]
+pub struct RunOptions {
+    /// Benchmark every stage with this config
+    pub bench: Option<BenchConfig>,
+    /// Ask each run of a part to stop once it exceeds this time
+    pub timeout: Option<Duration>,
+    /// Logs and progress reported by the first run of each part
+    pub output: Output,
+}
+
+/// A day waiting to be run on its input
+pub struct Job<'a> {
+    pub registration: &'a Registration,
+    pub input: String,
+    /// The example the input comes from, if any
+    pub example: Option<Example>,
+}
+
+impl<'a> Job<'a> {
+    /// A job on `input`, cleaned up the way the day expects unless `raw` is set
+    pub fn new(
+        registration: &'a Registration,
+        input: &str,
+        example: Option<Example>,
+        raw: bool,
+    ) -> Self {
+        let input = if raw {
+            input.to_string()
+        } else {
+            registration.normalize.apply(input).into_owned()
+        };
+        Job {
+            registration,
+            input,
+            example,
+        }
+    }
+
+    pub fn run(&self, parts: &[u8], options: &RunOptions) -> DayResult {
+        DayResult {
+            example: self.example.as_ref().map(|example| example.name.clone()),
+            ..run_day(self.registration, parts, &self.input, options)
+        }
+    }
+}
+
+/// Where the days of a run take their input from
+#[derive(Debug, Clone, Copy)]
+pub enum Source<'a> {
+    /// Each day's puzzle input from the input directory
+    Input(&'a Dirs),
+    /// A file, or stdin if the path is `-`
+    File(&'a Path),
+    /// Each day's examples with this name, or all of them for [`examples::ALL`]
+    Example(&'a Dirs, &'a str),
+}
+
+/// Why a selected day was left out of a run
+#[derive(Debug)]
+pub enum Skip {
+    NotImplemented,
+    Input(InputError),
+}
+
+impl fmt::Display for Skip {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Skip::NotImplemented => write!(f, "not implemented yet"),
+            Skip::Input(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+fn day_jobs(
+    registration: &'static Registration,
+    source: Source,
+    raw: bool,
+) -> Result<Vec<Job<'static>>, InputError> {
+    let Registration { year, day, .. } = *registration;
+    let input = match source {
+        Source::Input(dirs) => dirs.read_input(year, day)?,
+        Source::File(path) => input::read_path(path).map_err(|source| InputError::Read {
+            path: path.to_path_buf(),
+            source,
+        })?,
+        Source::Example(dirs, name) => {
+            return Ok(examples::select(dirs, year, day, name)?
+                .into_iter()
+                .map(|example| {
+                    let input = example.input.clone();
+                    Job::new(registration, &input, Some(example), raw)
+                })
+                .collect());
+        }
+    };
+    Ok(vec![Job::new(registration, &input, None, raw)])
+}
+
+/// The jobs for the given days of a year, in order, along with the days that can't run
+pub fn jobs(
+    year: u16,
+    days: &[u8],
+    source: Source,
+    raw: bool,
+) -> (Vec<Job<'static>>, Vec<(u8, Skip)>) {
+    let mut jobs = Vec::new();
+    let mut skipped = Vec::new();
+    for &day in days {
+        let Some(registration) = days::find(year, day) else {
+            skipped.push((day, Skip::NotImplemented));
+            continue;
+        };
+        match day_jobs(registration, source, raw) {
+            Ok(day_jobs) => jobs.extend(day_jobs),
+            Err(e) => skipped.push((day, Skip::Input(e))),
+        }
+    }
+    (jobs, skipped)
+}
+
+/// Run `f` once, counting its allocations if enabled, then benchmark it when a config
+/// is given. Failures are not benchmarked.
+fn measure<R>(
+    bench: Option<&BenchConfig>,
+    mut f: impl FnMut() -> Result<R, SolveError>,
+) -> (Result<R, SolveError>, Timing) {
+    let (result, elapsed, memory) = if alloc::is_enabled() {
+        let ((result, elapsed), memory) = alloc::measure(|| {
+            let start = Instant::now();
+            (f(), start.elapsed())
+        });
+        (result, elapsed, Some(memory))
+    } else {
+        let start = Instant::now();
+        (f(), start.elapsed(), None)
+    };
+
+    match (bench, result) {
+        (Some(config), Ok(_)) => {
+            let (result, stats) = bench::run(config, f);
+            let timing = Timing {
+                elapsed: stats.median,
+                iterations: stats.iterations,
+                stats: Some(stats),
+                memory,
+            };
+            (result, timing)
+        }
+        (_, result) => {
+            let timing = Timing {
+                elapsed,
+                iterations: 1,
+                stats: None,
+                memory,
+            };
+            (result, timing)
+        }
+    }
+}
+
+/// Parse the input of a day once and run the given parts on it,
+/// benchmarking every stage when a config is given.
+/// Each run of a part is asked to stop once it exceeds the timeout; only the first run
+/// reports logs, progress and debug values, so benchmark iterations stay quiet.
+pub fn run_day(
+    registration: &Registration,
+    parts: &[u8],
+    input: &str,
+    options: &RunOptions,
+) -> DayResult {
+    let Registration {
+        year,
+        day,
+        solution,
+        ..
+    } = *registration;
+    let bench = options.bench.as_ref();
+    let input_hash = input::input_hash(input);
+    let (parsed, parse) = measure(bench, || solution.parse_input(black_box(input)));
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let mut first = Some(
+                Context::new(options.timeout)
+                    .with_output(options.output, format!("day {} part {}", day, part)),
+            );
+            let mut values = Vec::new();
+            let (answer, timing) = match &parsed {
+                Ok(parsed) => measure(bench, || match first.take() {
+                    Some(ctx) => {
+                        let answer = solution.solve(part, black_box(parsed.as_ref()), &ctx);
+                        values = ctx.into_values();
+                        answer
+                    }
+                    None => {
+                        let ctx = Context::new(options.timeout);
+                        solution.solve(part, black_box(parsed.as_ref()), &ctx)
+                    }
+                }),
+                Err(e) => (Err(e.clone()), Timing::default()),
+            };
+            PartResult {
+                year,
+                day,
+                part,
+                answer: answer.map_err(|e| e.for_day(day)),
+                timing,
+                input_hash: input_hash.clone(),
+                values,
+            }
+        })
+        .collect();
+
+    DayResult {
+        year,
+        day,
+        parse,
+        parse_error: parsed.as_ref().err().map(|e| e.clone().for_day(day)),
+        parts,
+        input_hash,
+        concurrent: false,
+        example: None,
+    }
+}
+
+/// Run days concurrently on a pool of `threads` threads, returning the results in job order.
+/// Benchmarking is skipped, as concurrent timings are not comparable.
+pub fn run_parallel(
+    jobs: &[Job],
+    parts: &[u8],
+    options: &RunOptions,
+    threads: usize,
+) -> Result<Vec<DayResult>, ThreadPoolBuildError> {
+    let pool = ThreadPoolBuilder::new().num_threads(threads).build()?;
+    let results = pool.install(|| {
+        jobs.par_iter()
+            .map(|job| DayResult {
+                concurrent: true,
+                ..job.run(
+                    parts,
+                    &RunOptions {
+                        bench: None,
+                        ..*options
+                    },
+                )
+            })
+            .collect()
+    });
+    Ok(results)
+}
+
+/// Run jobs one after another, or concurrently on a pool of `threads` threads when there
+/// is more than one of each
+pub fn run_jobs(
+    jobs: &[Job],
+    parts: &[u8],
+    options: &RunOptions,
+    threads: usize,
+) -> Result<Vec<DayResult>, ThreadPoolBuildError> {
+    if threads > 1 && jobs.len() > 1 {
+        run_parallel(jobs, parts, options, threads)
+    } else {
+        Ok(jobs.iter().map(|job| job.run(parts, options)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_jobs() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("input.txt");
+        std::fs::write(&path, "(()\r\n").unwrap();
+
+        let (jobs, skipped) = super::jobs(DEFAULT_YEAR, &[1, 25], Source::File(&path), false);
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].input, "(()");
+        assert!(matches!(skipped[..], [(25, Skip::NotImplemented)]));
+
+        let (jobs, _) = super::jobs(DEFAULT_YEAR, &[1], Source::File(&path), true);
+        assert_eq!(jobs[0].input, "(()\r\n");
+
+        let dirs = Dirs {
+            inputs: dir.path().to_path_buf(),
+            examples: dir.path().join("examples"),
+        };
+        std::fs::create_dir(dir.path().join("2015")).unwrap();
+        std::fs::write(dirs.input_path(DEFAULT_YEAR, 1), "(((").unwrap();
+        let (jobs, _) = super::jobs(DEFAULT_YEAR, &[1], Source::Input(&dirs), false);
+        assert_eq!(jobs[0].input, "(((");
+        let (jobs, skipped) = super::jobs(DEFAULT_YEAR, &[1], Source::Example(&dirs, "a"), false);
+        assert!(jobs.is_empty());
+        assert!(matches!(
+            skipped[..],
+            [(1, Skip::Input(InputError::MissingExample { .. }))]
+        ));
+
+        let missing = dir.path().join("missing.txt");
+        let (jobs, skipped) = super::jobs(DEFAULT_YEAR, &[1], Source::File(&missing), false);
+        assert!(jobs.is_empty());
+        assert!(matches!(
+            skipped[..],
+            [(1, Skip::Input(InputError::Read { .. }))]
+        ));
+    }
+
+    #[test]
+    fn test_run_parallel() {
+        let inputs = [(3, "^>v<"), (1, "(()"), (2, "2x3x4")];
+        let jobs: Vec<_> = inputs
+            .iter()
+            .map(|&(day, input)| {
+                Job::new(days::find(DEFAULT_YEAR, day).unwrap(), input, None, false)
+            })
+            .collect();
+        let options = RunOptions::default();
+
+        let results = run_parallel(&jobs, &[1, 2], &options, 4).unwrap();
+        let order: Vec<_> = results.iter().map(|result| result.day).collect();
+        assert_eq!(order, [3, 1, 2]);
+        assert!(results.iter().all(|result| result.concurrent));
+
+        let sequential = run_jobs(&jobs, &[1, 2], &options, 1).unwrap();
+        assert!(sequential.iter().all(|result| !result.concurrent));
+        for (parallel, sequential) in results.iter().zip(&sequential) {
+            let answers = |result: &DayResult| -> Vec<_> {
+                result
+                    .parts
+                    .iter()
+                    .map(|part| part.answer.clone())
+                    .collect()
+            };
+            assert_eq!(answers(parallel), answers(sequential));
+        }
+    }
+}
@@ -0,0 +1,456 @@
+use super::alloc::MemStats;
+use super::bench::Stats;
+use super::{DayResult, PartResult, Timing};
+use crate::days::Answer;
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize, Serializer};
+use std::collections::BTreeSet;
+use std::time::Duration;
+
+/// How results are written to stdout
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    /// Human-readable output
+    Text,
+    /// A JSON array with one record per part
+    Json,
+    /// CSV with a header row and one record per part
+    Csv,
+}
+
+/// A single machine-readable result
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<&'a str>,
+    status: &'static str,
+    answer: Option<&'a Answer>,
+    error: Option<String>,
+    parse_ns: u64,
+    elapsed_ns: u64,
+    iterations: u64,
+    concurrent: bool,
+    input_hash: &'a str,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    stats: Option<StatsRecord>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    memory: Option<MemStats>,
+    #[serde(
+        skip_serializing_if = "<[_]>::is_empty",
+        serialize_with = "serialize_values"
+    )]
+    values: &'a [(String, String)],
+}
+
+/// Debug values as a JSON object, keeping the order they were recorded in
+fn serialize_values<S: Serializer>(
+    values: &&[(String, String)],
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_map(values.iter().map(|(name, value)| (name, value)))
+}
+
+/// Benchmark statistics in nanoseconds
+#[derive(Serialize)]
+struct StatsRecord {
+    samples: usize,
+    mean_ns: u64,
+    median_ns: u64,
+    min_ns: u64,
+    max_ns: u64,
+    stddev_ns: u64,
+    p95_ns: u64,
+    p99_ns: u64,
+}
+
+impl From<&Stats> for StatsRecord {
+    fn from(stats: &Stats) -> Self {
+        let ns = |d: Duration| d.as_nanos() as u64;
+        StatsRecord {
+            samples: stats.samples,
+            mean_ns: ns(stats.mean),
+            median_ns: ns(stats.median),
+            min_ns: ns(stats.min),
+            max_ns: ns(stats.max),
+            stddev_ns: ns(stats.stddev),
+            p95_ns: ns(stats.p95),
+            p99_ns: ns(stats.p99),
+        }
+    }
+}
+
+impl<'a> Record<'a> {
+    fn new(day: &'a DayResult, result: &'a PartResult) -> Self {
+        Record {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            example: day.example.as_deref(),
+            status: status(result),
+            answer: result
+                .answer
+                .as_ref()
+                .ok()
+                .filter(|answer| **answer != Answer::None),
+            error: result.answer.as_ref().err().map(|e| e.to_string()),
+            parse_ns: day.parse.elapsed.as_nanos() as u64,
+            elapsed_ns: result.timing.elapsed.as_nanos() as u64,
+            iterations: result.timing.iterations,
+            concurrent: day.concurrent,
+            input_hash: &result.input_hash,
+            stats: result.timing.stats.as_ref().map(StatsRecord::from),
+            memory: result.timing.memory,
+            values: &result.values,
+        }
+    }
+}
+
+/// Whether a part succeeded, found the puzzle has no answer, failed or ran out of time
+fn status(result: &PartResult) -> &'static str {
+    match &result.answer {
+        Ok(Answer::None) => "no_answer",
+        Ok(_) => "ok",
+        Err(e) if e.timed_out => "timed_out",
+        Err(_) => "error",
+    }
+}
+
+fn records(results: &[DayResult]) -> Vec<Record<'_>> {
+    results
+        .iter()
+        .flat_map(|day| day.parts.iter().map(move |part| Record::new(day, part)))
+        .collect()
+}
+
+/// Print all results as a JSON array
+pub fn print_json(results: &[DayResult]) {
+    let records = records(results);
+    let json = serde_json::to_string_pretty(&records).expect("Failed to serialize results");
+    println!("{}", json);
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Print all results as CSV with a header row. Debug values are left out.
+pub fn print_csv(results: &[DayResult]) {
+    println!(
+        "year,day,part,example,status,answer,error,parse_ns,elapsed_ns,iterations,concurrent,input_hash,\
+         samples,mean_ns,median_ns,min_ns,max_ns,stddev_ns,p95_ns,p99_ns,\
+         peak_bytes,allocations,allocated_bytes"
+    );
+    for record in records(results) {
+        let stats = match &record.stats {
+            Some(s) => format!(
+                "{},{},{},{},{},{},{},{}",
+                s.samples,
+                s.mean_ns,
+                s.median_ns,
+                s.min_ns,
+                s.max_ns,
+                s.stddev_ns,
+                s.p95_ns,
+                s.p99_ns
+            ),
+            None => ",,,,,,,".to_string(),
+        };
+        let memory = match &record.memory {
+            Some(m) => format!("{},{},{}", m.peak_bytes, m.allocations, m.allocated_bytes),
+            None => ",,".to_string(),
+        };
+        println!(
+            "{},{},{},{},{},{},{},{},{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            csv_field(record.example.unwrap_or_default()),
+            record.status,
+            csv_field(&record.answer.map(|a| a.to_string()).unwrap_or_default()),
+            csv_field(record.error.as_deref().unwrap_or_default()),
+            record.parse_ns,
+            record.elapsed_ns,
+            record.iterations,
+            record.concurrent,
+            record.input_hash,
+            stats,
+            memory
+        );
+    }
+}
+
+/// Format a byte count with a binary unit, e.g. `3.8 MiB`
+fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut value = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", value, UNITS[unit])
+}
+
+fn print_timing(timing: &Timing) {
+    if let Some(memory) = &timing.memory {
+        println!(
+            "Memory: peak {}, {} allocations, {} allocated",
+            format_bytes(memory.peak_bytes),
+            memory.allocations,
+            format_bytes(memory.allocated_bytes)
+        );
+    }
+    match &timing.stats {
+        Some(stats) => {
+            println!(
+                "Median time ({} runs in {} samples): {:?}",
+                stats.iterations, stats.samples, stats.median
+            );
+            println!(
+                "  min {:?}  max {:?}  mean {:?}  stddev {:?}  p95 {:?}  p99 {:?}\n",
+                stats.min, stats.max, stats.mean, stats.stddev, stats.p95, stats.p99
+            );
+        }
+        None => println!("Time: {:?}\n", timing.elapsed),
+    }
+}
+
+/// Print the parse time and the result of each part of a single day
+pub fn print_day(result: &DayResult) {
+    if let Some(example) = &result.example {
+        println!("Example {}\n", example);
+    }
+    if let Some(e) = &result.parse_error {
+        eprintln!("Parse failed: {}", e);
+        return;
+    }
+    println!("Parse");
+    print_timing(&result.parse);
+
+    for part in &result.parts {
+        match &part.answer {
+            Ok(Answer::Text(text)) => println!("Part {}:\n{}", part.part, text),
+            Ok(answer) => println!("Part {}: {}", part.part, answer),
+            Err(e) => {
+                eprintln!("Part {} failed: {}", part.part, e);
+                print_values(part);
+                eprintln!();
+                continue;
+            }
+        }
+        print_values(part);
+        print_timing(&part.timing);
+    }
+}
+
+fn print_values(part: &PartResult) {
+    for (name, value) in &part.values {
+        println!("  {} = {}", name, value);
+    }
+}
+
+/// Multi-line answers are shortened to their first line to keep the table intact
+fn table_cell(answer: &Answer) -> String {
+    let text = answer.to_string();
+    match text.split_once('\n') {
+        Some((first, _)) => format!("{}…", first),
+        None => text,
+    }
+}
+
+/// Print one table row per day with the parse time and the answer and timing of each part,
+/// followed by the total time
+pub fn print_summary(results: &[DayResult]) {
+    let mut parts: Vec<u8> = results
+        .iter()
+        .flat_map(|day| day.parts.iter().map(|r| r.part))
+        .collect();
+    parts.sort_unstable();
+    parts.dedup();
+
+    let memory = results
+        .iter()
+        .flat_map(|day| &day.parts)
+        .any(|r| r.timing.memory.is_some());
+
+    let mut header = vec!["Day".to_string(), "Parse".to_string()];
+    for part in &parts {
+        header.push(format!("Part {}", part));
+        header.push("Time".to_string());
+        if memory {
+            header.push("Peak".to_string());
+        }
+    }
+
+    let mut rows = vec![header];
+    for day in results {
+        let label = match &day.example {
+            Some(example) => format!("{} ({})", day.day, example),
+            None => day.day.to_string(),
+        };
+        let parse = match &day.parse_error {
+            Some(_) => "error".to_string(),
+            None => format!("{:?}", day.parse.elapsed),
+        };
+        let mut row = vec![label, parse];
+        for &part in &parts {
+            match day.parts.iter().find(|r| r.part == part) {
+                Some(_) if day.parse_error.is_some() => {
+                    row.extend(["-".to_string(), "-".to_string()])
+                }
+                Some(result) => match &result.answer {
+                    Ok(answer) => {
+                        row.push(table_cell(answer));
+                        row.push(format!("{:?}", result.timing.elapsed));
+                    }
+                    Err(e) if e.timed_out => row.extend(["timeout".to_string(), "-".to_string()]),
+                    Err(_) => row.extend(["error".to_string(), "-".to_string()]),
+                },
+                None => row.extend(["-".to_string(), "-".to_string()]),
+            }
+            if memory {
+                let peak = day
+                    .parts
+                    .iter()
+                    .find(|r| r.part == part)
+                    .and_then(|r| r.timing.memory)
+                    .map_or("-".to_string(), |m| format_bytes(m.peak_bytes));
+                row.push(peak);
+            }
+        }
+        rows.push(row);
+    }
+
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|col| {
+            rows.iter()
+                .map(|row| row[col].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    for (i, row) in rows.iter().enumerate() {
+        let cells: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .map(|(cell, &width)| format!("{:<width$}", cell, width = width))
+            .collect();
+        println!("{}", cells.join("  ").trim_end());
+        if i == 0 {
+            let total_width = widths.iter().sum::<usize>() + 2 * (widths.len() - 1);
+            println!("{}", "-".repeat(total_width));
+        }
+    }
+
+    let values: Vec<_> = results
+        .iter()
+        .flat_map(|day| &day.parts)
+        .filter(|r| !r.values.is_empty())
+        .collect();
+    if !values.is_empty() {
+        println!();
+        for part in values {
+            let values: Vec<_> = part
+                .values
+                .iter()
+                .map(|(name, value)| format!("{} = {}", name, value))
+                .collect();
+            println!("Day {} part {}: {}", part.day, part.part, values.join(", "));
+        }
+    }
+
+    let errors: Vec<_> = results
+        .iter()
+        .flat_map(|day| match &day.parse_error {
+            Some(e) => vec![format!("Parse failed: {}", e)],
+            None => day
+                .parts
+                .iter()
+                .filter_map(|r| {
+                    let e = r.answer.as_ref().err()?;
+                    Some(format!("Part {} failed: {}", r.part, e))
+                })
+                .collect(),
+        })
+        .collect();
+    if !errors.is_empty() {
+        eprintln!();
+        for error in errors {
+            eprintln!("{}", error);
+        }
+    }
+
+    let total: Duration = results
+        .iter()
+        .map(|day| day.parse.elapsed + day.parts.iter().map(|r| r.timing.elapsed).sum::<Duration>())
+        .sum();
+    let part_count: usize = results.iter().map(|day| day.parts.len()).sum();
+    let day_count = results
+        .iter()
+        .map(|day| day.day)
+        .collect::<BTreeSet<_>>()
+        .len();
+    println!(
+        "\nTotal: {:?} ({} days, {} parts)",
+        total, day_count, part_count
+    );
+    if results.iter().any(|day| day.concurrent) {
+        println!("Days ran concurrently; timings are not comparable to isolated runs");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(4_000_000), "3.8 MiB");
+        assert_eq!(format_bytes(3 << 30), "3.0 GiB");
+    }
+
+    #[test]
+    fn test_record_no_answer() {
+        let day = DayResult {
+            year: 2015,
+            day: 1,
+            parse: Timing::default(),
+            parse_error: None,
+            parts: vec![
+                PartResult::test(1, Answer::None),
+                PartResult::test(1, Answer::from("none")),
+            ],
+            input_hash: String::new(),
+            concurrent: false,
+            example: None,
+        };
+
+        let records = records(std::slice::from_ref(&day));
+        assert_eq!(records[0].status, "no_answer");
+        assert_eq!(records[0].answer, None);
+        assert_eq!(records[1].status, "ok");
+        assert_eq!(records[1].answer, Some(&Answer::from("none")));
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("1234"), "1234");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("line\nbreak"), "\"line\nbreak\"");
+    }
+}
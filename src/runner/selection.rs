@@ -0,0 +1,92 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// A set of days given on the command line, e.g. `1-7,9`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DaySelection {
+    days: Vec<u8>,
+}
+
+impl DaySelection {
+    /// Every possible puzzle day (1-25)
+    pub fn all() -> Self {
+        DaySelection {
+            days: (1..=25).collect(),
+        }
+    }
+
+    /// A single day
+    pub fn single(day: u8) -> Self {
+        DaySelection { days: vec![day] }
+    }
+
+    /// The selected days in ascending order, without duplicates
+    pub fn days(&self) -> &[u8] {
+        &self.days
+    }
+}
+
+fn parse_day(s: &str) -> Result<u8, String> {
+    let day: u8 = s
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid day '{}'", s.trim()))?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is out of range (1-25)", day));
+    }
+    Ok(day)
+}
+
+impl FromStr for DaySelection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut days = Vec::new();
+
+        for item in s.split(',') {
+            if let Some((start, end)) = item.split_once('-') {
+                let (start, end) = (parse_day(start)?, parse_day(end)?);
+                if start > end {
+                    return Err(format!("invalid range '{}'", item.trim()));
+                }
+                days.extend(start..=end);
+            } else {
+                days.push(parse_day(item)?);
+            }
+        }
+
+        days.sort_unstable();
+        days.dedup();
+        Ok(DaySelection { days })
+    }
+}
+
+impl fmt::Display for DaySelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let days: Vec<String> = self.days.iter().map(|d| d.to_string()).collect();
+        write!(f, "{}", days.join(","))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let selection: DaySelection = "1-7,9".parse().unwrap();
+        assert_eq!(selection.days(), &[1, 2, 3, 4, 5, 6, 7, 9]);
+
+        let selection: DaySelection = "9, 3,3-4".parse().unwrap();
+        assert_eq!(selection.days(), &[3, 4, 9]);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!("".parse::<DaySelection>().is_err());
+        assert!("0".parse::<DaySelection>().is_err());
+        assert!("26".parse::<DaySelection>().is_err());
+        assert!("7-1".parse::<DaySelection>().is_err());
+        assert!("1-x".parse::<DaySelection>().is_err());
+    }
+}
@@ -0,0 +1,313 @@
+use crate::utils::input::Dirs;
+use std::fmt;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const TEMPLATE: &str = r#"use crate::days::{Answer, Context, SolveError, Solution};
+
+pub struct Day{{DAY}};
+
+solution!(
+    Day{{DAY}},
+    day: {{DAY_NUMBER}},
+    title: "TODO",
+    tags: [],
+);
+
+impl Solution for Day{{DAY}} {
+    type Parsed = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Parsed, SolveError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(&self, _lines: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        // TODO: Implement part 1
+        Err(SolveError::new("not implemented"))
+    }
+
+    fn part2(&self, _lines: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+        // TODO: Implement part 2
+        Err(SolveError::new("not implemented"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "not implemented yet"]
+    fn test_part1() {
+        let input = "";
+        let solution = Day{{DAY}};
+        assert_eq!(solution.solve_part1(input).unwrap(), "expected".into());
+    }
+
+    #[test]
+    #[ignore = "not implemented yet"]
+    fn test_part2() {
+        let input = "";
+        let solution = Day{{DAY}};
+        assert_eq!(solution.solve_part2(input).unwrap(), "expected".into());
+    }
+}
+"#;
+
+const YEAR_TEMPLATE: &str = "pub const YEAR: u16 = {{YEAR}};\n\ndays! {\n}\n";
+
+/// What happened to a single file while scaffolding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Step {
+    Created(PathBuf),
+    Registered(PathBuf),
+    Skipped(PathBuf),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Created(path) => write!(f, "Created {}", path.display()),
+            Step::Registered(path) => write!(f, "Registered module in {}", path.display()),
+            Step::Skipped(path) => write!(f, "Skipped {} (already exists)", path.display()),
+        }
+    }
+}
+
+/// Create `path` with `contents` unless it already exists
+fn create_new(root: &Path, path: impl AsRef<Path>, contents: &str) -> io::Result<Step> {
+    let path = path.as_ref();
+    let full = root.join(path);
+    if let Some(parent) = full.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    match fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(&full)
+    {
+        Ok(mut file) => {
+            file.write_all(contents.as_bytes())?;
+            Ok(Step::Created(path.to_path_buf()))
+        }
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(Step::Skipped(path.to_path_buf())),
+        Err(e) => Err(e),
+    }
+}
+
+/// Add `module` to the list in the `block! { ... }` macro call of a module, keeping the
+/// list sorted. Returns `None` if it was already listed.
+fn register_module(contents: &str, block: &str, module: &str) -> io::Result<Option<String>> {
+    let invalid = || {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("no `{}! {{ ... }}` block found", block),
+        )
+    };
+
+    let open = format!("\n{}! {{", block);
+    let start = contents.find(&open).ok_or_else(invalid)? + open.len();
+    let end = start + contents[start..].find('}').ok_or_else(invalid)?;
+
+    let mut modules: Vec<&str> = contents[start..end]
+        .split(',')
+        .map(str::trim)
+        .filter(|m| !m.is_empty())
+        .collect();
+    if modules.contains(&module) {
+        return Ok(None);
+    }
+    modules.push(module);
+    modules.sort_unstable();
+
+    let list: String = modules.iter().map(|m| format!("    {},\n", m)).collect();
+    Ok(Some(format!(
+        "{}\n{}{}",
+        &contents[..start],
+        list,
+        &contents[end..]
+    )))
+}
+
+/// Add `module` to the list of `block!` in the module at `path`, recording the step
+fn register(
+    root: &Path,
+    path: &str,
+    block: &str,
+    module: &str,
+    steps: &mut Vec<Step>,
+) -> io::Result<()> {
+    let contents = fs::read_to_string(root.join(path))?;
+    if let Some(updated) = register_module(&contents, block, module)? {
+        fs::write(root.join(path), updated)?;
+        steps.push(Step::Registered(PathBuf::from(path)));
+    }
+    Ok(())
+}
+
+/// Scaffold a new day below `root`: the solution module from a template, its entry in
+/// the year's `days!` list (setting up the year module first if it is the year's first
+/// day), and empty input and example files in the configured input and example
+/// directories (relative to `root` unless absolute). Existing files are never overwritten, so
+/// running it again only fills in whatever is missing.
+pub fn new_day(root: &Path, dirs: &Dirs, year: u16, day: u8) -> io::Result<Vec<Step>> {
+    let padded = format!("{:02}", day);
+    let source = TEMPLATE
+        .replace("{{DAY}}", &padded)
+        .replace("{{DAY_NUMBER}}", &day.to_string());
+    let year_dir = format!("src/days/y{}", year);
+
+    let mut steps = vec![create_new(
+        root,
+        format!("{}/day{}.rs", year_dir, padded),
+        &source,
+    )?];
+
+    let year_mod = format!("{}/mod.rs", year_dir);
+    let year_source = YEAR_TEMPLATE.replace("{{YEAR}}", &year.to_string());
+    if let step @ Step::Created(_) = create_new(root, &year_mod, &year_source)? {
+        steps.push(step);
+    }
+    register(
+        root,
+        "src/days/mod.rs",
+        "years",
+        &format!("y{}", year),
+        &mut steps,
+    )?;
+    register(
+        root,
+        &year_mod,
+        "days",
+        &format!("day{}", padded),
+        &mut steps,
+    )?;
+
+    steps.push(create_new(root, dirs.input_path(year, day), "")?);
+    steps.push(create_new(root, dirs.example_path(year, day), "")?);
+
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const MOD_RS: &str = "mod x;\n\nyears! {\n    y2015,\n}\n\nmod answer;\n";
+    const YEAR_RS: &str = "pub const YEAR: u16 = 2015;\n\ndays! {\n    day01,\n    day09,\n}\n";
+
+    fn project() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir_all(dir.path().join("src/days/y2015")).unwrap();
+        fs::write(dir.path().join("src/days/mod.rs"), MOD_RS).unwrap();
+        fs::write(dir.path().join("src/days/y2015/mod.rs"), YEAR_RS).unwrap();
+        dir
+    }
+
+    fn dirs() -> Dirs {
+        Dirs {
+            inputs: "inputs".into(),
+            examples: "examples".into(),
+        }
+    }
+
+    #[test]
+    fn test_new_day() {
+        let dir = project();
+        let steps = new_day(dir.path(), &dirs(), 2015, 8).unwrap();
+
+        assert_eq!(
+            steps,
+            vec![
+                Step::Created("src/days/y2015/day08.rs".into()),
+                Step::Registered("src/days/y2015/mod.rs".into()),
+                Step::Created("inputs/2015/day08.txt".into()),
+                Step::Created("examples/2015/day08.txt".into()),
+            ]
+        );
+
+        let source = fs::read_to_string(dir.path().join("src/days/y2015/day08.rs")).unwrap();
+        assert!(source.contains("pub struct Day08;"));
+        assert!(source.contains("day: 8,"));
+
+        let year_rs = fs::read_to_string(dir.path().join("src/days/y2015/mod.rs")).unwrap();
+        assert_eq!(
+            year_rs,
+            "pub const YEAR: u16 = 2015;\n\ndays! {\n    day01,\n    day08,\n    day09,\n}\n"
+        );
+        let mod_rs = fs::read_to_string(dir.path().join("src/days/mod.rs")).unwrap();
+        assert_eq!(mod_rs, MOD_RS);
+    }
+
+    #[test]
+    fn test_new_day_in_configured_dirs() {
+        let dir = project();
+        let dirs = Dirs {
+            inputs: "data/inputs".into(),
+            examples: dir.path().join("data/examples"),
+        };
+        let steps = new_day(dir.path(), &dirs, 2015, 8).unwrap();
+
+        assert_eq!(steps[2], Step::Created("data/inputs/2015/day08.txt".into()));
+        assert!(dir.path().join("data/inputs/2015/day08.txt").is_file());
+        assert!(dir.path().join("data/examples/2015/day08.txt").is_file());
+    }
+
+    #[test]
+    fn test_new_year() {
+        let dir = project();
+        let steps = new_day(dir.path(), &dirs(), 2016, 1).unwrap();
+
+        assert_eq!(
+            steps,
+            vec![
+                Step::Created("src/days/y2016/day01.rs".into()),
+                Step::Created("src/days/y2016/mod.rs".into()),
+                Step::Registered("src/days/mod.rs".into()),
+                Step::Registered("src/days/y2016/mod.rs".into()),
+                Step::Created("inputs/2016/day01.txt".into()),
+                Step::Created("examples/2016/day01.txt".into()),
+            ]
+        );
+
+        let year_rs = fs::read_to_string(dir.path().join("src/days/y2016/mod.rs")).unwrap();
+        assert_eq!(
+            year_rs,
+            "pub const YEAR: u16 = 2016;\n\ndays! {\n    day01,\n}\n"
+        );
+        let mod_rs = fs::read_to_string(dir.path().join("src/days/mod.rs")).unwrap();
+        assert_eq!(
+            mod_rs,
+            "mod x;\n\nyears! {\n    y2015,\n    y2016,\n}\n\nmod answer;\n"
+        );
+    }
+
+    #[test]
+    fn test_new_day_is_idempotent() {
+        let dir = project();
+        new_day(dir.path(), &dirs(), 2015, 8).unwrap();
+        fs::write(
+            dir.path().join("src/days/y2015/day08.rs"),
+            "// work in progress",
+        )
+        .unwrap();
+
+        let steps = new_day(dir.path(), &dirs(), 2015, 8).unwrap();
+        assert!(steps.iter().all(|step| matches!(step, Step::Skipped(_))));
+
+        let source = fs::read_to_string(dir.path().join("src/days/y2015/day08.rs")).unwrap();
+        assert_eq!(source, "// work in progress");
+        let year_rs = fs::read_to_string(dir.path().join("src/days/y2015/mod.rs")).unwrap();
+        assert_eq!(year_rs.matches("day08").count(), 1);
+    }
+
+    #[test]
+    fn test_missing_days_block() {
+        let dir = project();
+        fs::write(dir.path().join("src/days/y2015/mod.rs"), "pub mod day01;\n").unwrap();
+        let error = new_day(dir.path(), &dirs(), 2015, 2).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -1,17 +1,307 @@
-#![allow(dead_code)]
-
+use std::borrow::Cow;
+use std::env;
+use std::fmt;
 use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Environment variable overriding the directory puzzle inputs are read from
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+pub const DEFAULT_INPUT_DIR: &str = "inputs";
+pub const DEFAULT_EXAMPLE_DIR: &str = "examples";
+
+/// Name of the example read from the single `examples/YYYY/dayNN.txt` file
+pub const DEFAULT_EXAMPLE: &str = "default";
+
+/// Where puzzle inputs and examples are read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dirs {
+    pub inputs: PathBuf,
+    pub examples: PathBuf,
+}
+
+impl Default for Dirs {
+    /// `inputs` and `examples`, with the input directory from `AOC_INPUT_DIR` if set
+    fn default() -> Self {
+        Dirs::new(DEFAULT_INPUT_DIR, DEFAULT_EXAMPLE_DIR)
+    }
+}
+
+impl Dirs {
+    /// The given directories, e.g. as configured in `aoc.toml`, except that `AOC_INPUT_DIR`
+    /// overrides the input directory when set
+    pub fn new(inputs: impl Into<PathBuf>, examples: impl Into<PathBuf>) -> Self {
+        let inputs = env::var_os(INPUT_DIR_VAR)
+            .filter(|dir| !dir.is_empty())
+            .map_or_else(|| inputs.into(), PathBuf::from);
+        Dirs {
+            inputs,
+            examples: examples.into(),
+        }
+    }
+
+    /// Path of the puzzle input for a specific day, e.g. `inputs/2015/day01.txt`
+    pub fn input_path(&self, year: u16, day: u8) -> PathBuf {
+        self.inputs
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// Where the puzzle input of a day was kept before inputs were split up by year
+    pub fn legacy_input_path(&self, day: u8) -> PathBuf {
+        self.inputs.join(format!("day{:02}.txt", day))
+    }
+
+    /// Path of the example input for a specific day
+    pub fn example_path(&self, year: u16, day: u8) -> PathBuf {
+        self.examples
+            .join(year.to_string())
+            .join(format!("day{:02}.txt", day))
+    }
+
+    /// Directory holding the named examples for a specific day
+    pub fn example_dir(&self, year: u16, day: u8) -> PathBuf {
+        self.examples
+            .join(year.to_string())
+            .join(format!("day{:02}", day))
+    }
+
+    /// Every non-empty example input file of a day: `examples/YYYY/dayNN.txt`, then
+    /// `examples/YYYY/dayNN/*.txt` sorted by name
+    pub fn example_files(&self, year: u16, day: u8) -> Result<Vec<PathBuf>, InputError> {
+        example_files_in(self.example_path(year, day), self.example_dir(year, day))
+    }
+
+    /// Read input file for a specific day. An empty file, such as the stub `new` creates,
+    /// counts as missing.
+    pub fn read_input(&self, year: u16, day: u8) -> Result<String, InputError> {
+        read_input_from(
+            year,
+            day,
+            self.input_path(year, day),
+            self.legacy_input_path(day),
+        )
+    }
+
+    /// Read example input file for a specific day. Like inputs, an empty file counts as
+    /// missing.
+    pub fn read_example(&self, year: u16, day: u8) -> Result<String, InputError> {
+        read_example_from(
+            day,
+            self.example_path(year, day),
+            self.example_dir(year, day),
+        )
+    }
+}
+
+/// Why an input or example could not be read
+#[derive(Debug)]
+pub enum InputError {
+    MissingInput {
+        year: u16,
+        day: u8,
+        path: PathBuf,
+    },
+    /// The input is only in the flat layout used before inputs were split up by year
+    NotMigrated {
+        path: PathBuf,
+        legacy: PathBuf,
+    },
+    MissingExample {
+        day: u8,
+        /// The requested example, or `None` for any example
+        name: Option<String>,
+        /// Where the default example would be
+        path: PathBuf,
+        /// Where named examples would be
+        dir: PathBuf,
+        /// The example files that do exist for the day
+        available: Vec<PathBuf>,
+    },
+    Read {
+        path: PathBuf,
+        source: io::Error,
+    },
+}
+
+fn example_name(name: &Option<String>) -> String {
+    match name {
+        Some(name) => format!("example {:?}", name),
+        None => "examples".to_string(),
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::MissingInput { year, day, path } => write!(
+                f,
+                "no input for day {} at {}; download it with `{} fetch --year {} --day {}`",
+                day,
+                path.display(),
+                env!("CARGO_PKG_NAME"),
+                year,
+                day
+            ),
+            InputError::NotMigrated { path, legacy } => write!(
+                f,
+                "no input at {}, but found {}; move inputs into per-year directories with `{} migrate`",
+                path.display(),
+                legacy.display(),
+                env!("CARGO_PKG_NAME")
+            ),
+            InputError::MissingExample {
+                day,
+                name,
+                path,
+                dir,
+                available,
+            } if available.is_empty() => write!(
+                f,
+                "no {} for day {}; add {} or {}",
+                example_name(name),
+                day,
+                path.display(),
+                dir.join("<name>.txt").display()
+            ),
+            InputError::MissingExample {
+                day,
+                name,
+                available,
+                ..
+            } => {
+                let files: Vec<_> = available.iter().map(|p| p.display().to_string()).collect();
+                write!(
+                    f,
+                    "no {} for day {}; available: {}",
+                    example_name(name),
+                    day,
+                    files.join(", ")
+                )
+            }
+            InputError::Read { path, source } => write!(f, "{}: {}", path.display(), source),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+fn read_file(path: &Path) -> Result<Option<String>, InputError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(source) => Err(InputError::Read {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+/// Whether `path` is a file with any contents; empty files, such as the stubs `new`
+/// creates, count as missing
+fn has_contents(path: &Path) -> bool {
+    fs::metadata(path).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0)
+}
+
+fn example_files_in(path: PathBuf, dir: PathBuf) -> Result<Vec<PathBuf>, InputError> {
+    let mut files = Vec::new();
+    if has_contents(&path) {
+        files.push(path);
+    }
+
+    let entries = match fs::read_dir(&dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(files),
+        Err(source) => return Err(InputError::Read { path: dir, source }),
+    };
+    let mut named = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|source| InputError::Read {
+                path: dir.clone(),
+                source,
+            })?
+            .path();
+        if path.extension().is_some_and(|ext| ext == "txt") && has_contents(&path) {
+            named.push(path);
+        }
+    }
+    named.sort();
+    files.extend(named);
+    Ok(files)
+}
+
+/// Read input file for a specific day from the default directories; see [`Dirs`]
+pub fn read_input(year: u16, day: u8) -> Result<String, InputError> {
+    Dirs::default().read_input(year, day)
+}
+
+fn read_input_from(
+    year: u16,
+    day: u8,
+    path: PathBuf,
+    legacy: PathBuf,
+) -> Result<String, InputError> {
+    if let Some(contents) = read_file(&path)?.filter(|contents| !contents.is_empty()) {
+        return Ok(contents);
+    }
+    if legacy.is_file() {
+        return Err(InputError::NotMigrated { path, legacy });
+    }
+    Err(InputError::MissingInput { year, day, path })
+}
+
+fn read_example_from(day: u8, path: PathBuf, dir: PathBuf) -> Result<String, InputError> {
+    match read_file(&path)?.filter(|contents| !contents.is_empty()) {
+        Some(contents) => Ok(contents),
+        None => Err(InputError::MissingExample {
+            day,
+            name: Some(DEFAULT_EXAMPLE.to_string()),
+            available: example_files_in(path.clone(), dir.clone())?,
+            path,
+            dir,
+        }),
+    }
+}
+
+/// How input text is cleaned up before it reaches a solution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Normalize {
+    /// Strip a byte order mark, convert CRLF line endings and trim trailing newlines
+    #[default]
+    Full,
+    /// Strip a byte order mark and convert CRLF line endings, but keep all whitespace
+    LineEndings,
+}
+
+impl Normalize {
+    pub fn apply(self, input: &str) -> Cow<'_, str> {
+        let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+        let input = match self {
+            Normalize::Full => input.trim_end_matches(['\r', '\n']),
+            Normalize::LineEndings => input,
+        };
+        if input.contains("\r\n") {
+            Cow::Owned(input.replace("\r\n", "\n"))
+        } else {
+            Cow::Borrowed(input)
+        }
+    }
+}
 
-/// Read input file for a specific day
-pub fn read_input(day: u8) -> String {
-    let path = format!("inputs/day{:02}.txt", day);
-    fs::read_to_string(&path).unwrap_or_else(|_| panic!("Failed to read input file: {}", path))
+/// Read input from a file, or from stdin if the path is `-`
+pub fn read_path(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        io::read_to_string(io::stdin())
+    } else {
+        fs::read_to_string(path)
+    }
 }
 
-/// Read example input file for a specific day
-pub fn read_example(day: u8) -> String {
-    let path = format!("examples/day{:02}.txt", day);
-    fs::read_to_string(&path).unwrap_or_else(|_| String::new())
+/// Hash the input contents so results can be tied to the input they were computed on
+pub fn input_hash(input: &str) -> String {
+    format!("{:x}", md5::compute(input))
 }
 
 /// Parse input into lines
@@ -63,3 +353,117 @@ where
         .map(|s| s.trim().parse().expect("Failed to parse CSV value"))
         .collect()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        let input = "\u{feff}ab\r\n\r\ncd\r\n\n";
+        assert_eq!(Normalize::Full.apply(input), "ab\n\ncd");
+        assert_eq!(Normalize::LineEndings.apply(input), "ab\n\ncd\n\n");
+        assert_eq!(Normalize::Full.apply("  x  \n"), "  x  ");
+        assert!(matches!(
+            Normalize::Full.apply("ab\ncd\n"),
+            Cow::Borrowed("ab\ncd")
+        ));
+    }
+
+    #[test]
+    fn test_read_input_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("2015/day07.txt");
+        let legacy = dir.path().join("day07.txt");
+        fs::create_dir_all(dir.path().join("2015")).unwrap();
+
+        fs::write(&path, "").unwrap();
+        let error = read_input_from(2015, 7, path.clone(), legacy.clone()).unwrap_err();
+        assert!(matches!(error, InputError::MissingInput { .. }));
+
+        fs::write(&path, "123 -> a").unwrap();
+        let input = read_input_from(2015, 7, path, legacy).unwrap();
+        assert_eq!(input, "123 -> a");
+    }
+
+    #[test]
+    fn test_read_example_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("day07.txt");
+        let named = dir.path().join("day07");
+        fs::create_dir_all(&named).unwrap();
+        fs::write(&path, "").unwrap();
+        fs::write(named.join("empty.txt"), "").unwrap();
+        fs::write(named.join("small.txt"), "123 -> a").unwrap();
+
+        let error = read_example_from(7, path.clone(), named.clone()).unwrap_err();
+        let InputError::MissingExample { available, .. } = error else {
+            panic!("expected a missing example, got {:?}", error);
+        };
+        assert_eq!(available, [named.join("small.txt")]);
+
+        fs::write(&path, "123 -> a").unwrap();
+        let example = read_example_from(7, path.clone(), named.clone()).unwrap();
+        assert_eq!(example, "123 -> a");
+        assert_eq!(
+            example_files_in(path.clone(), named.clone()).unwrap(),
+            [path, named.join("small.txt")]
+        );
+    }
+
+    #[test]
+    fn test_error_messages() {
+        let error = InputError::MissingInput {
+            year: 2015,
+            day: 7,
+            path: PathBuf::from("inputs/2015/day07.txt"),
+        };
+        assert_eq!(
+            error.to_string(),
+            "no input for day 7 at inputs/2015/day07.txt; \
+             download it with `aoc2015 fetch --year 2015 --day 7`"
+        );
+
+        let error = InputError::NotMigrated {
+            path: PathBuf::from("inputs/2015/day07.txt"),
+            legacy: PathBuf::from("inputs/day07.txt"),
+        };
+        assert_eq!(
+            error.to_string(),
+            "no input at inputs/2015/day07.txt, but found inputs/day07.txt; \
+             move inputs into per-year directories with `aoc2015 migrate`"
+        );
+
+        let dirs = Dirs {
+            inputs: PathBuf::from(DEFAULT_INPUT_DIR),
+            examples: PathBuf::from(DEFAULT_EXAMPLE_DIR),
+        };
+        let error = InputError::MissingExample {
+            day: 7,
+            name: None,
+            path: dirs.example_path(2015, 7),
+            dir: dirs.example_dir(2015, 7),
+            available: vec![],
+        };
+        assert_eq!(
+            error.to_string(),
+            "no examples for day 7; add examples/2015/day07.txt or examples/2015/day07/<name>.txt"
+        );
+
+        let error = InputError::MissingExample {
+            day: 7,
+            name: Some("big".to_string()),
+            path: dirs.example_path(2015, 7),
+            dir: dirs.example_dir(2015, 7),
+            available: vec![
+                PathBuf::from("examples/2015/day07.txt"),
+                PathBuf::from("examples/2015/day07/small.txt"),
+            ],
+        };
+        assert_eq!(
+            error.to_string(),
+            "no example \"big\" for day 7; \
+             available: examples/2015/day07.txt, examples/2015/day07/small.txt"
+        );
+    }
+}
@@ -1,5 +1,3 @@
-#![allow(dead_code)]
-
 /// Calculate the greatest common divisor using Euclidean algorithm
 pub fn gcd(mut a: i64, mut b: i64) -> i64 {
     while b != 0 {
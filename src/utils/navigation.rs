@@ -1,5 +1,3 @@
-#![allow(dead_code)]
-
 /// Apply a direction character to coordinates
 /// Returns the new (x, y) after moving
 pub fn apply_direction(x: i32, y: i32, direction: char) -> (i32, i32) {
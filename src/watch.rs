@@ -0,0 +1,256 @@
+use crate::utils::input::Dirs;
+use serde_json::Value;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, SystemTime};
+
+/// Options for a watch session
+pub struct Options {
+    /// Config file passed on to the runs
+    pub config: PathBuf,
+    /// Where the day's input and examples are, to watch them for changes
+    pub dirs: Dirs,
+    pub year: u16,
+    pub day: u8,
+    pub part: Option<u8>,
+    pub example: Option<String>,
+    pub interval: Duration,
+}
+
+/// Totals from the `test result:` lines of `cargo test` output
+#[derive(Debug, Default, PartialEq, Eq)]
+struct TestSummary {
+    passed: usize,
+    failed: usize,
+}
+
+fn watched_paths(dirs: &Dirs, year: u16, day: u8) -> Vec<PathBuf> {
+    vec![
+        PathBuf::from(format!("src/days/y{}/day{:02}.rs", year, day)),
+        dirs.input_path(year, day),
+        dirs.example_path(year, day),
+        dirs.example_dir(year, day),
+    ]
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// Modification times of the watched files and of the files in watched directories;
+/// missing files have none
+fn snapshot(paths: &[PathBuf]) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut times = Vec::new();
+    for path in paths {
+        times.push((path.clone(), modified(path)));
+        if let Ok(entries) = fs::read_dir(path) {
+            let mut files: Vec<_> = entries.flatten().map(|entry| entry.path()).collect();
+            files.sort();
+            times.extend(files.into_iter().map(|file| {
+                let time = modified(&file);
+                (file, time)
+            }));
+        }
+    }
+    times
+}
+
+fn cargo() -> Command {
+    Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()))
+}
+
+fn parse_test_summary(output: &str) -> Option<TestSummary> {
+    let mut summary: Option<TestSummary> = None;
+    for line in output.lines() {
+        let Some(counts) = line.trim().strip_prefix("test result: ") else {
+            continue;
+        };
+        let totals = summary.get_or_insert_with(TestSummary::default);
+        for count in counts.split(['.', ';']) {
+            match count.split_whitespace().collect::<Vec<_>>()[..] {
+                [n, "passed"] => totals.passed += n.parse::<usize>().unwrap_or(0),
+                [n, "failed"] => totals.failed += n.parse::<usize>().unwrap_or(0),
+                _ => {}
+            }
+        }
+    }
+    summary
+}
+
+/// The first compiler error, to hint at why a build failed
+fn first_error(output: &str) -> Option<&str> {
+    output
+        .lines()
+        .find(|line| line.starts_with("error"))
+        .map(str::trim)
+}
+
+/// Summarize each part from the runner's JSON output
+fn parse_parts(output: &str) -> Option<Vec<String>> {
+    // Anything the solutions print themselves comes before the JSON array
+    let start = output.find("\n[").map_or(0, |i| i + 1);
+    let records: Vec<Value> = serde_json::from_str(output[start..].trim()).ok()?;
+
+    let parts = records
+        .iter()
+        .map(|record| {
+            let part = match record["example"].as_str() {
+                Some(example) => format!("{} {}", example, record["part"]),
+                None => record["part"].to_string(),
+            };
+            match (&record["answer"], &record["error"]) {
+                (_, Value::String(error)) => format!("part {}: ✗ {}", part, error),
+                (Value::Number(answer), _) => format!(
+                    "part {}: {} ({:.1?})",
+                    part,
+                    answer,
+                    Duration::from_nanos(record["elapsed_ns"].as_u64().unwrap_or(0))
+                ),
+                (Value::String(answer), _) => format!(
+                    "part {}: {} ({:.1?})",
+                    part,
+                    answer.lines().next().unwrap_or_default(),
+                    Duration::from_nanos(record["elapsed_ns"].as_u64().unwrap_or(0))
+                ),
+                _ => format!("part {}: no answer", part),
+            }
+        })
+        .collect();
+    Some(parts)
+}
+
+fn run_tests(year: u16, day: u8) -> io::Result<(bool, String)> {
+    let output = cargo()
+        .args([
+            "test",
+            "--quiet",
+            &format!("days::y{}::day{:02}::", year, day),
+        ])
+        .output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    let line = match parse_test_summary(&stdout) {
+        Some(TestSummary { passed, failed: 0 }) => format!("tests: {} passed", passed),
+        Some(TestSummary { passed, failed }) => {
+            format!("tests: {} failed, {} passed", failed, passed)
+        }
+        None => match first_error(&stderr) {
+            Some(error) => format!("build failed: {}", error),
+            None => "build failed".to_string(),
+        },
+    };
+    Ok((output.status.success(), line))
+}
+
+fn run_parts(options: &Options) -> io::Result<(bool, String)> {
+    let mut command = cargo();
+    command.args(["run", "--quiet", "--"]);
+    command.arg("--config").arg(&options.config);
+    command.args(["--year", &options.year.to_string()]);
+    command.args(["--day", &options.day.to_string(), "--format", "json"]);
+    if let Some(part) = options.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    if let Some(example) = &options.example {
+        command.args(["--example", example]);
+    }
+
+    let output = command.output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let line = match parse_parts(&stdout) {
+        Some(parts) => parts.join(" | "),
+        None => {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            stderr
+                .lines()
+                .next()
+                .unwrap_or("run failed")
+                .trim()
+                .to_string()
+        }
+    };
+    Ok((output.status.success(), line))
+}
+
+/// Run the day's tests and parts and print a single pass/fail line
+fn run_once(options: &Options) -> io::Result<()> {
+    let (tests_ok, tests) = run_tests(options.year, options.day)?;
+    let (parts_ok, parts) = if tests_ok {
+        run_parts(options)?
+    } else {
+        (false, "parts skipped".to_string())
+    };
+
+    let mark = if tests_ok && parts_ok { "✓" } else { "✗" };
+    println!("{} day {:02} | {} | {}", mark, options.day, tests, parts);
+    Ok(())
+}
+
+/// Re-run the day whenever its source, input or example changes, until interrupted
+pub fn watch(options: &Options) -> io::Result<()> {
+    let paths = watched_paths(&options.dirs, options.year, options.day);
+    println!(
+        "Watching {} (Ctrl-C to stop)",
+        paths
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    let mut last = snapshot(&paths);
+    run_once(options)?;
+    loop {
+        thread::sleep(options.interval);
+        let current = snapshot(&paths);
+        if current != last {
+            last = current;
+            run_once(options)?;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_test_summary() {
+        let output = "\nrunning 2 tests\n..\ntest result: ok. 2 passed; 0 failed; 0 ignored; \
+                      0 measured; 39 filtered out; finished in 0.01s\n";
+        assert_eq!(
+            parse_test_summary(output),
+            Some(TestSummary {
+                passed: 2,
+                failed: 0
+            })
+        );
+
+        let output = "test result: FAILED. 1 passed; 1 failed; 0 ignored; 0 measured; \
+                      39 filtered out; finished in 0.01s\n";
+        assert_eq!(
+            parse_test_summary(output),
+            Some(TestSummary {
+                passed: 1,
+                failed: 1
+            })
+        );
+        assert_eq!(parse_test_summary("error[E0308]: mismatched types"), None);
+    }
+
+    #[test]
+    fn test_parse_parts() {
+        let output = "found: 1\n[\n  {\"part\": 1, \"answer\": 42, \"error\": null, \"elapsed_ns\": 1500},\n  \
+                      {\"part\": 2, \"answer\": null, \"error\": \"day 1: bad input\", \"elapsed_ns\": 0}\n]\n";
+        assert_eq!(
+            parse_parts(output).unwrap(),
+            vec!["part 1: 42 (1.5µs)", "part 2: ✗ day 1: bad input"]
+        );
+        assert_eq!(parse_parts("not json"), None);
+    }
+}
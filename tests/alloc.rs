@@ -0,0 +1,22 @@
+//! Counting is global to the process, so this runs in its own test binary where nothing
+//! else allocates while it measures
+use aoc2015::runner::alloc::{self, CountingAllocator};
+use std::hint::black_box;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[test]
+fn test_measure() {
+    alloc::enable();
+    let (_, stats) = alloc::measure(|| {
+        let grid = black_box(vec![vec![0i32; 1000]; 100]);
+        drop(grid);
+        black_box(vec![0u8; 1000]);
+    });
+
+    assert!(stats.allocations >= 102);
+    assert!(stats.allocated_bytes >= 100 * 4000 + 1000);
+    assert!(stats.peak_bytes >= 100 * 4000);
+    assert!(stats.peak_bytes < stats.allocated_bytes);
+}
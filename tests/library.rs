@@ -0,0 +1,95 @@
+use aoc2015::days::{self, Answer, Context, Level, Solution, SolveError};
+use aoc2015::runner::{self, answers, RunOptions, Source};
+use aoc2015::utils::grid::{Direction, Grid, Position};
+use aoc2015::utils::{input, math};
+
+/// A solution defined outside the crate, registered the same way as the crate's own days
+mod y2099 {
+    pub const YEAR: u16 = 2099;
+
+    pub mod day01 {
+        use super::super::*;
+
+        pub struct Day01;
+
+        aoc2015::solution!(Day01, day: 1, title: "Sum and Product", tags: ["math"]);
+
+        impl Solution for Day01 {
+            type Parsed = Vec<i64>;
+
+            fn parse(&self, text: &str) -> Result<Self::Parsed, SolveError> {
+                Ok(input::parse_integers(text))
+            }
+
+            fn part1(&self, numbers: &Self::Parsed, ctx: &Context) -> Result<Answer, SolveError> {
+                if ctx.enabled(Level::Debug) {
+                    ctx.log(Level::Debug, format_args!("{} numbers", numbers.len()));
+                }
+                Ok(numbers.iter().sum::<i64>().into())
+            }
+
+            fn part2(&self, numbers: &Self::Parsed, _ctx: &Context) -> Result<Answer, SolveError> {
+                Ok(math::lcm_vec(numbers).into())
+            }
+        }
+    }
+}
+
+#[test]
+fn test_registry() {
+    let registration = days::find(2015, 1).unwrap();
+    assert_eq!(registration.title, "Not Quite Lisp");
+    assert!(days::year(2015).unwrap().len() >= 7);
+    assert!(days::find(2099, 1).is_none());
+
+    let result = runner::run_day(registration, &[1, 2], "()())", &RunOptions::default());
+    let answers: Vec<_> = result
+        .parts
+        .iter()
+        .map(|part| part.answer.clone())
+        .collect();
+    assert_eq!(answers, vec![Ok(Answer::Int(-1)), Ok(Answer::Int(5))]);
+
+    let result = runner::run_day(registration, &[3], "()())", &RunOptions::default());
+    assert_eq!(
+        result.parts[0].answer.as_ref().unwrap_err().reason,
+        "there is no part 3"
+    );
+}
+
+#[test]
+fn test_external_solution() {
+    use y2099::day01::{Day01, REGISTRATION};
+
+    assert_eq!(Day01.solve_part1("4\n6\n").unwrap(), 10.into());
+    assert_eq!((REGISTRATION.year, REGISTRATION.day), (2099, 1));
+
+    let result = runner::run_day(&REGISTRATION, &[2], "4\n6\n", &RunOptions::default());
+    assert_eq!(result.parts[0].answer, Ok(Answer::Int(12)));
+}
+
+#[test]
+fn test_run_and_verify() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("input.txt");
+    let answers = dir.path().join(answers::DEFAULT_PATH);
+    std::fs::write(&input, "(()))\n").unwrap();
+    std::fs::write(&answers, "[2015.day01]\npart1 = -1\n").unwrap();
+
+    let (jobs, skipped) = runner::jobs(2015, &[1], Source::File(&input), false);
+    assert!(skipped.is_empty());
+    let results = runner::run_jobs(&jobs, &[1, 2], &RunOptions::default(), 1).unwrap();
+    let parts: Vec<_> = results.iter().flat_map(|day| &day.parts).collect();
+
+    let verification = answers::verify(&answers, &parts, true).unwrap();
+    assert!(!verification.any_wrong());
+    assert_eq!(verification.recorded, 1);
+}
+
+#[test]
+fn test_utils() {
+    let grid = Grid::new(input::parse_char_grid("ab\ncd"));
+    let below = Position::new(0, 1).move_in(Direction::South);
+    assert_eq!(grid.get(below), Some(&'d'));
+    assert_eq!(math::gcd(12, 18), 6);
+}